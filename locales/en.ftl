@@ -0,0 +1,525 @@
+## Screen chrome
+
+zoom-hint = Zoomed ({ $key } to restore)
+too-small = Terminal too small
+too-small-need = Need at least { $size }
+debug-log-title = Debug Log ({ $path })
+toast-info = Info
+toast-error = Error
+workspace-new = new
+key-unbound = unbound
+
+## Toasts
+
+toast-config-error = Config error: { $error }
+error-config-preset = unknown keymap preset '{ $preset }'
+error-config-context = unknown key context '{ $context }'
+error-config-action = unknown action '{ $action }'
+error-config-key = invalid key '{ $key }'
+error-config-language = unknown language '{ $language }'
+error-geoip-open = GeoIP database { $path }: { $error }
+toast-session-saved = Saved session to { $path }
+toast-session-save-failed = Saving session failed: { $error }
+toast-session-loaded = Loaded session { $name }
+toast-session-empty = Session { $name } has no workspaces
+error-session-name = '{ $name }' can't be a session name: no '/', '\' or '..'
+toast-session-load-failed = Loading session failed: { $error }
+toast-report-written = Wrote report to { $path }
+toast-workspace-reset = Cleared every input and result
+toast-exported = Exported to { $path }
+toast-export-failed = Export failed: { $error }
+toast-unknown-command = Unknown command: { $command }
+
+## Popups
+
+interfaces-none = No IPv4 interfaces found (Esc to close)
+interfaces-title = Local Interfaces (Enter to use, Esc to cancel)
+interfaces-failed = Listing interfaces failed: { $error }
+export-formats = Export Format
+export-preview = Preview (Enter to save, Esc to cancel)
+export-format-ios-acl = Cisco IOS access list
+export-format-ios-route = Cisco IOS static route
+export-format-ios-dhcp = Cisco IOS DHCP pool
+export-format-nftables = nftables
+export-format-iptables = iptables
+export-format-linux-route = Linux route
+export-format-bind-zones = BIND reverse zones
+export-format-hosts = hosts file
+export-format-terraform = Terraform aws_subnet
+export-format-ansible = Ansible inventory
+error-export-no-subnet = Calculate a subnet before exporting
+palette-title = Command Palette (type to filter, Enter to run, Esc to cancel):
+palette-actions = Actions
+session-save-title = Save Session As (Enter to save, Esc to cancel):
+session-load-title = Load Session (type to filter, Enter to load, Esc to cancel):
+session-list = Sessions in { $path }
+
+## Calculator
+
+calc-enter-ip = Enter IP Address (Left/Right to pick an octet):
+calc-enter-mask = Enter Subnet Mask or /prefix (Left/Right to pick an octet):
+calc-enter-hosts = Enter Number of Hosts Needed:
+calc-enter-subnets = Enter Number of Subnets Needed:
+calc-input-help = Press '{ $ip }' to Input IP, '{ $mask }' for Subnet, '{ $hosts }' for Hosts Needed, '{ $subnets }' for Subnets Needed
+field-ip = IP
+field-subnet = Subnet
+field-hosts-needed = Hosts Needed
+field-subnets-needed = Subnets Needed
+field-cidr = CIDR
+field-mac = MAC
+field-prefix = Prefix
+calc-zones-more = { $zones }, ... ({ $count } zones)
+calc-sizing = /{ $prefix } ({ $mask }), { $usable } usable - press '{ $key }' to use
+calc-subnetting = borrow { $bits } bits -> /{ $prefix } ({ $mask }), { $subnets } subnets x { $hosts } hosts
+calc-dhcp-static = static { $first }-{ $last },{" "}
+calc-dhcp = gateway { $gateway }, { $excluded }pool { $first }-{ $last } ({ $percent }% static, { $shrink }/{ $grow } to adjust)
+calc-cloud-hosts = { $hosts } ({ $cloud } reserves { $reserved })
+calc-cloud-off = off ({ $key } for AWS/Azure/GCP)
+cloud-network = network
+cloud-vpc-router = VPC router
+cloud-default-gateway = default gateway
+cloud-dns = DNS
+cloud-azure-dns = Azure DNS
+cloud-future-use = future use
+cloud-broadcast = broadcast
+result-network = Network Address
+result-broadcast = Broadcast Address
+result-subnet-count = Subnet Count
+result-host-count = Host Count
+result-cloud = Cloud Reserved
+result-class = IP Class
+result-scope = Scope
+result-reverse-zone = Reverse Zone
+result-hosts-prefix = Smallest Prefix for Hosts
+result-subnetting = Subnetting
+result-dhcp = DHCP Scope
+result-location = Location
+calc-results = Subnet Calculation
+calc-history = History
+calc-warnings = Warnings
+suggestions-title = Tab to complete
+
+help-membership = Membership
+help-overlaps = Overlaps
+help-tree = Tree
+help-range = Range to CIDR
+help-hosts = Enumerate Hosts
+help-quiz = Quiz
+help-ipv6 = IPv6
+help-arithmetic = IP Math
+help-cleanup = Cleanup
+help-interfaces = Interfaces
+help-export = Export
+help-report = Report
+help-cheat-sheet = Cheat Sheet
+help-transition = IPv6 Forms
+help-whois = Whois
+help-compare = Compare
+help-explain = Explain
+help-zoom = Zoom
+help-swap = Swap IP/Mask
+help-clear-field = Clear Field
+help-reset = Reset
+help-sweep = Ping Sweep
+help-next-subnet = Next Subnet
+help-prev-subnet = Prev Subnet
+help-next-workspace = Next Workspace
+help-new-workspace = New
+help-close-workspace = Close
+help-save = Save
+help-load = Load
+help-undo = Undo
+help-redo = Redo
+help-palette = Palette
+help-quit = Quit
+
+warning-cloud-prefix = { $cloud } subnets must be between /{ $shortest } and /{ $longest }
+warning-inside = { $ip } is inside { $scope } space { $block } ({ $rfc })
+warning-overlaps = { $first }-{ $last } overlaps { $scope } space { $block } ({ $rfc })
+
+class-a = Class A
+class-b = Class B
+class-c = Class C
+class-d = Class D (multicast)
+class-e = Class E (experimental)
+
+scope-this-network = "This" network
+scope-private = Private
+scope-shared-cgnat = Shared (CGNAT)
+scope-loopback = Loopback
+scope-link-local = Link-local
+scope-protocol-assignments = IETF protocol assignments
+scope-documentation = Documentation
+scope-relay-6to4 = 6to4 relay anycast
+scope-benchmarking = Benchmarking
+scope-multicast = Multicast
+scope-reserved = Reserved
+scope-broadcast = Limited broadcast
+scope-public = Public
+
+## Side panes
+
+transition-empty = Calculate an address to convert it
+transition-title = IPv6 Transition ({ $key } to hide)
+rdap-waiting = Asking the registry...
+rdap-org = Org
+rdap-network = Network
+rdap-handle = Handle
+rdap-country = Country
+rdap-asn = ASN
+rdap-allocated = Allocated
+rdap-title = RDAP { $ip } ({ $key } to hide)
+rdap-unsupported = This build has no RDAP support; rebuild with --features rdap
+rdap-unreadable = Unreadable RDAP response: { $error }
+rdap-no-network = No IP network in the RDAP response
+error-rdap-no-address = Calculate an address before looking up its owner
+error-rdap-not-public = { $ip } is not public, so no registry holds it
+compare-title = Compare ({ $key } to unpin)
+compare-pinned = Pinned
+compare-current = Current
+compare-overlap = Overlap
+compare-empty = Calculate a subnet to compare it with the pinned one
+compare-identical = The same block
+compare-pinned-contains = The current block is { $share } of the pinned { $block }
+compare-current-contains = The pinned block is { $share } of the current { $block }
+compare-halves = Disjoint halves of { $block }
+compare-disjoint = Disjoint, no addresses in common
+error-compare-no-subnet = Calculate a subnet before pinning it
+explain-empty = Calculate a subnet to see how it is worked out
+explain-title-empty = Explain ({ $back } to go back)
+explain-title = Step { $step } of { $steps }: { $title } ({ $back } to go back)
+explain-steps = Steps ({ $up }/{ $down })
+explain-network = Network Address
+explain-broadcast = Broadcast Address
+explain-range = Host Range
+explain-hosts = Host Count
+explain-subnets = Subnets from Borrowed Bits
+explain-first-host = First
+explain-last-host = Last
+explain-old-mask = Before
+explain-new-mask = After
+explain-and-rule = AND the IP with the mask: a network bit is 1 only where both bits are 1, so the host bits all become 0.
+explain-octet-and = Octet { $n }: { $ip } AND { $mask } = { $result }
+explain-or-rule = Invert the mask to get the wildcard, then OR it with the network: every host bit becomes 1.
+explain-octet-or = Octet { $n }: { $network } OR { $wildcard } = { $result }
+explain-first = First host = network + 1 = { $first }
+explain-last = Last host = broadcast - 1 = { $last }
+explain-range-rfc3021 = A /{ $prefix } sets no network or broadcast address aside (RFC 3021), so every address is a host.
+explain-host-bits = /{ $prefix } leaves 32 - { $prefix } = { $bits } host bits.
+explain-addresses = 2^{ $bits } = { $addresses } addresses in the block.
+explain-usable = { $addresses } - 2 for the network and broadcast = { $hosts } usable hosts.
+explain-usable-rfc3021 = RFC 3021 counts all { $hosts } of them as usable hosts.
+explain-borrowed = Borrowing { $bits } host bits moves the prefix from /{ $from } to /{ $to }.
+explain-subnet-count = 2^{ $bits } = { $subnets } subnets, enough for the { $required } needed.
+explain-classful-count = 2^{ $bits } = { $subnets } subnets of the classful /{ $class } network.
+explain-subnet-hosts = Each subnet keeps { $bits } host bits for { $hosts } usable hosts.
+explain-no-borrowed = No host bits are borrowed. Enter Subnets Needed to split the block.
+cheat-sheet-title = Cheat Sheet ({ $up }/{ $down } to scroll, { $key } to hide)
+binary-title-empty = Binary
+binary-title = Binary - prefix /{ $prefix } ({ $shrink }/{ $grow } to resize)
+binary-ip = IP
+binary-mask = Mask
+binary-network = Network
+binary-broadcast = Broadcast
+
+## Table columns
+
+column-mask = Mask
+column-wildcard = Wildcard
+column-hosts = Hosts
+column-block = Block
+column-relation = Relation
+column-overlaps = Overlaps
+column-host = Host
+column-address = Address
+column-label = Label
+column-name = Name
+column-status = Status
+column-rtt = RTT
+
+## List titles
+
+filter-typing = {" "}- filter: /{ $query }_ (Enter to keep, Esc to clear)
+filter-applied = {" "}- filter: /{ $query }
+filter-hint = {" "}- { $key } to filter
+list-ignored = {" "}- ignored: { $entries }
+
+## Membership
+
+membership-enter-ip = Enter Candidate IP:
+membership-enter-cidr = Enter CIDR Block:
+membership-help = Press '{ $ip }' to Input IP, '{ $cidr }' for CIDR, { $back } to go back
+membership-invalid-ip = Invalid IP address
+membership-invalid-cidr = Invalid CIDR, expected e.g. 10.0.0.0/22
+membership-outside = { $ip } is NOT inside { $block }
+membership-host = host #{ $number }
+membership-network = network address
+membership-broadcast = broadcast address
+membership-inside =
+    { $ip } is inside { $block }
+    Offset: { $offset } of { $size }
+    Position: { $position }
+membership-title = Membership Check
+
+## Overlaps
+
+overlap-load-file = Load From File
+overlap-read-failed = Cannot read { $path }: { $error }
+overlap-enter-list = Enter CIDRs, one per line (Esc to analyze):
+overlap-enter-path = Enter file path (Enter to load):
+overlap-help = Press '{ $edit }' to Edit List, '{ $load }' to Load File, { $back } to go back
+overlap-duplicate = duplicate
+overlap-contains = contains
+overlap-title = { $blocks } blocks, { $pairs } overlapping pairs ({ $up }/{ $down } to scroll)
+routes-imported = Imported { $count } routes from { $format } output
+routes-imported-without-default = Imported { $count } routes from { $format } output, leaving out the default route
+
+## Containment tree
+
+tree-enter-parent = Enter Parent Block (Enter to build):
+tree-enter-children = Enter Child Blocks, one per line (Esc to build):
+tree-help = Press '{ $parent }' for Parent, '{ $edit }' to Edit Children, { $collapse } to Collapse, { $back } to go back
+tree-invalid-parent = Invalid parent block
+tree-children = Child Blocks
+tree-free = free { $free } of { $size } ({ $percent }%)
+tree-title = Containment Tree
+tree-outside = {" "}- outside parent: { $blocks }
+usage-title = Utilization of { $block } - { $percent }% used (█ used, ▒ partly, · free)
+usage-no-gaps = No gaps: fully allocated
+usage-gaps = Gaps: { $gaps }
+
+## Range to CIDR
+
+range-enter = Enter IP Range, e.g. 10.0.0.17 - 10.0.3.200:
+range-help = Press '{ $edit }' to Input Range, { $back } to go back
+range-invalid = Invalid range, expected e.g. 10.0.0.17 - 10.0.3.200
+range-title = { $blocks } blocks covering { $addresses } addresses ({ $up }/{ $down } to scroll)
+
+## IPv6
+
+ipv6-enter-address = Enter IPv6 Address, e.g. 2001:db8::1 or fe80::1%eth0:
+ipv6-help = Press '{ $edit }' to Input Address, { $back } to go back
+ipv6-compressed = Compressed
+ipv6-expanded = Expanded
+ipv6-network = Network
+ipv6-embedded = IPv4
+ipv6-zone = Zone ID
+ipv6-zone-unused = { $zone } (only meaningful on link-local fe80::/10)
+ipv6-title-note = IPv6 Forms - { $note }
+ipv6-title = IPv6 Forms ('{ $key }' for a random ULA /48)
+ipv6-ula-source = Generated from { $source }
+ipv6-bad-prefix-length = '/{ $prefix }' is not a prefix length
+ipv6-bad-address = '{ $address }' is not an IPv6 address
+ipv6-empty-zone = Zone ID after '%' is empty
+ipv6-bad-zone = Zone ID can't contain '{ $character }'
+ipv6-bad-prefix = '{ $prefix }' is not an IPv6 prefix
+ipv6-kind-mapped = IPv4-mapped
+ipv6-kind-6to4 = 6to4
+ipv6-kind-nat64 = NAT64
+ipv6-kind-teredo = Teredo
+ula-source-mac = SHA-1 of the time and { $interface }'s EUI-64
+ula-source-random = the OS random number generator
+ula-failed = Generating a ULA failed: { $error }
+eui64-enter-mac = Enter MAC Address, e.g. 00:1a:2b:3c:4d:5e:
+eui64-enter-prefix = Enter /64 Prefix, e.g. 2001:db8:1:2::/64:
+eui64-help = EUI-64: Press '{ $mac }' for MAC, '{ $prefix }' for Prefix
+eui64-step-mac = 1. MAC:
+eui64-step-insert = 2. Insert ff:fe:
+eui64-step-flip = 3. Flip U/L bit:
+eui64-step-append = 4. Append to prefix:
+eui64-title = EUI-64 Address
+eui64-needs-64 = EUI-64 needs a /64 prefix, not /{ $length }
+eui64-bad-mac = '{ $mac }' is not a MAC address
+
+## IP arithmetic
+
+arith-enter = Enter +N or -N within the subnet, IP + N / IP - N, or IP - IP:
+arith-help = Press '{ $edit }' to Input Expression, { $back } to go back
+arith-title-block = Result (offsets are within { $block })
+arith-title = Result
+arith-usage = Expected an offset like +130 or -1, IP + N / IP - N, or IP - IP
+arith-no-subnet = Calculate a subnet first to offset into it
+arith-out-of-space = { $expression } leaves the IPv4 address space
+arith-inside = { $address } is inside { $block }
+arith-outside = { $address } is outside { $block }
+arith-past-broadcast = +{ $n } is past the broadcast address { $broadcast }; { $block } ends at +{ $last }
+arith-before-network = -{ $n } is before the network address { $network }; { $block } starts at -{ $last }
+arith-role-network = the network address
+arith-role-broadcast = the broadcast address
+arith-role-host = host { $number } of { $count }
+arith-address = Address: { $address }
+arith-from-network = +{ $n } from the network is { $role }
+arith-from-broadcast = -{ $n } from the broadcast is { $role }
+arith-distance = Distance: { $gap } addresses from { $start } to { $end }
+arith-between = { $between } addresses between them, { $inclusive } counting both ends
+arith-spanned = Spanned exactly by { $block }
+arith-not-spanned = No single prefix spans exactly; it takes { $count } blocks
+arith-covering = Smallest block containing both: { $block } ({ $size } addresses)
+
+## Cleanup
+
+cleanup-enter = Paste CIDRs, any separators (Esc to clean up):
+cleanup-help = Press '{ $edit }' to Edit List, { $back } to go back
+cleanup-title = { $entries } entries, { $unique } unique, { $merged } merged, { $addresses } addresses ({ $up }/{ $down } to scroll)
+
+## Hosts
+
+hosts-title = Hosts { $selected } of { $count } - page { $page } of { $pages }
+hosts-marked = {" "}- { $count } marked
+hosts-enter-jump = Jump to host number:
+hosts-enter-search = Search:
+hosts-enter-port = TCP port to check on the marked hosts (all if none):
+hosts-enter-label = Label for { $host } (empty to remove):
+hosts-help = '{ $jump }' to Jump, '{ $search }' to Search, '{ $next }' for Next Match, { $mark } to Mark, '{ $port }' to Check a Port, '{ $label }' to Label, '{ $names }' for DNS Names, { $page_up }/{ $page_down }, { $back } to go back
+hosts-out-of-range = Host number must be 1..{ $count }
+hosts-no-match = No host matches "{ $query }"
+hosts-no-match-within = No host in the next { $limit } matches "{ $query }"
+hosts-enter-number = Enter a host number
+hosts-enter-port-number = Enter a port number 1-65535
+
+## Quiz
+
+quiz-question = Question
+quiz-answer = Your Answer (Enter to submit, Esc to go back)
+quiz-correct = Correct! Press { $key } for the next question
+quiz-wrong =
+    Wrong, the answer is { $answer }
+    Press { $key } for the next question
+quiz-result = Result
+quiz-score =
+    Score: { $correct }/{ $asked }
+    Streak: { $streak }
+    Best Streak: { $best }
+quiz-title = Subnetting Quiz
+quiz-network = What is the network address of { $block }?
+quiz-broadcast = What is the broadcast of { $block }?
+quiz-first-host = What is the first usable host of { $block }?
+quiz-last-host = What is the last usable host of { $block }?
+quiz-usable-hosts = How many usable hosts does { $block } have?
+quiz-mask = What is the dotted subnet mask of /{ $prefix }?
+
+## Line mode
+
+inline-intro = Subnet calculator, line mode. Enter an empty address to quit.
+inline-ip = IP address, optionally with /prefix:
+inline-mask = Subnet mask or prefix length:
+inline-hosts = Hosts needed, or Enter to skip:
+inline-subnets = Subnets needed, or Enter to skip:
+inline-bad-ip = { $input } is not an IPv4 address
+inline-bad-mask = { $input } is not a subnet mask or prefix length
+inline-warning = Warning: { $warning }
+inline-range = { $first } to { $last }
+inline-sizing = /{ $prefix } ({ $mask }), { $usable } usable
+inline-dhcp = gateway { $gateway }, { $excluded }pool { $first }-{ $last } ({ $percent }% static)
+result-host-range = Host Range
+
+## Report
+
+report-title = Subnet Calculation Report
+report-generated = Generated { $time } UTC
+report-inputs = Inputs
+report-results = Results
+report-ip = IP Address
+report-mask = Subnet Mask
+report-wildcard = Wildcard Mask
+report-cloud = { $cloud }: { $reserved } ({ $usable } usable)
+report-labels = Host Labels
+report-binary = Binary (| marks the /{ $prefix } boundary)
+error-report-no-subnet = Calculate a subnet before writing a report
+error-report-write = Writing the report failed: { $error }
+
+## Ping sweep
+
+error-rdns-no-nameserver = No nameserver for reverse DNS: none in /etc/resolv.conf and no `nameserver` in config.toml
+error-sweep-no-subnet = Calculate a subnet before sweeping it
+error-sweep-too-large = { $block } is too large to sweep, the limit is { $limit } hosts
+error-probe-too-many = Too many hosts to probe, the limit is { $limit }
+sweep-progress = Progress
+sweep-rate = { $finished } / { $total } - { $rate } hosts/s
+sweep-probe-port = Port { $port }
+sweep-up = up
+sweep-open = open
+sweep-closed = closed
+sweep-no-answer = no answer
+sweep-down = down
+sweep-kind-scan = scan
+sweep-kind-sweep = sweep
+sweep-running = running
+sweep-cancelled = cancelled
+sweep-done = done
+sweep-cancel = cancel
+sweep-go-back = go back
+sweep-title = { $probe } { $kind } { $state }: { $answered } { $answer }, { $finished } of { $total } probed ({ $switch } to switch probe, { $names } for DNS names, { $up }/{ $down } to scroll, { $back_key } to { $back })
+
+## Command palette action descriptions
+
+action-quit = Quit the application
+action-command-line = Run an action by name
+action-command-palette = Search every action
+action-new-workspace = Open a new workspace tab
+action-close-workspace = Close the current workspace tab
+action-next-workspace = Switch to the next workspace
+action-prev-workspace = Switch to the previous workspace
+action-save-session = Save all workspaces to a session file
+action-load-session = Load a saved session
+action-undo = Undo the last edit in the focused field
+action-redo = Redo the last undone edit
+action-up = Move up
+action-down = Move down
+action-page-up = Move up a page
+action-page-down = Move down a page
+action-home = Go to the first entry
+action-end = Go to the last entry
+action-back = Return to the calculator
+action-toggle = Collapse a tree block or mark a host
+action-submit = Submit the answer or ask the next question
+action-focus-ip = Edit the IP address
+action-focus-mask = Edit the subnet mask
+action-focus-hosts = Edit the number of hosts needed
+action-focus-subnets = Edit the number of subnets needed
+action-calculate = Calculate the subnet
+action-use-hosts-prefix = Use the prefix sized for the hosts needed
+action-next-subnet = Step to the next subnet of the same size
+action-prev-subnet = Step to the previous subnet of the same size
+action-grow-prefix = Lengthen the prefix by one bit
+action-shrink-prefix = Shorten the prefix by one bit
+action-open-membership = Check whether an IP is inside a CIDR block
+action-open-overlap = Find overlapping CIDR blocks
+action-open-tree = Show a containment tree of CIDR blocks
+action-open-range = Convert an IP range to CIDR blocks
+action-open-hosts = Enumerate the hosts of the subnet
+action-open-quiz = Practice subnetting
+action-open-ipv6 = Compress or expand an IPv6 address, or derive one with EUI-64
+action-open-arithmetic = Offset into the subnet, add to an address or measure between two
+action-open-cleanup = Sort, deduplicate and merge a list of CIDRs
+action-generate-ula = Generate a random unique local IPv6 /48 (RFC 4193)
+action-open-sweep = Ping sweep the subnet
+action-switch-probe = Switch the sweep between ICMP and TCP
+action-port-scan = Check a TCP port on the marked hosts
+action-label-host = Name or annotate the selected host
+action-resolve-names = Show reverse DNS names of the listed hosts
+action-shrink-static-range = Give more of the DHCP scope to the pool
+action-grow-static-range = Keep more of the DHCP scope for static addresses
+action-cycle-cloud = Account for AWS, Azure or GCP reserved addresses
+action-toggle-cheat-sheet = Show or hide the netmask cheat sheet
+action-toggle-transition = Show the address as IPv4-mapped, 6to4 and NAT64
+action-clear-field = Empty the field being typed in
+action-reset = Clear every input and result in the workspace
+action-swap = Swap the IP address and subnet mask
+action-rdap = Look up who holds a public address over RDAP
+action-pin-compare = Pin the calculation to compare others against
+action-explain = Show the worked solution step by step
+action-toggle-zoom = Expand the main pane to fill the screen, or restore it
+action-toggle-debug-log = Show or hide recent log lines
+action-pick-interface = Prefill from a local network interface
+action-export = Export the subnet as ACL, route or infrastructure-as-code snippets
+action-export-report = Write a plain-text report of the calculation
+action-focus-cidr = Edit the CIDR block
+action-focus-mac = Edit the MAC address
+action-edit-list = Edit the block list
+action-load-file = Load blocks from a file
+action-focus-parent = Edit the parent block
+action-edit-range = Edit the IP range
+action-jump-to-host = Jump to a host number
+action-search = Search or filter the list
+action-next-match = Go to the next search match
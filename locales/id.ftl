@@ -0,0 +1,525 @@
+## Tampilan
+
+zoom-hint = Diperbesar ({ $key } untuk memulihkan)
+too-small = Terminal terlalu kecil
+too-small-need = Butuh minimal { $size }
+debug-log-title = Log Debug ({ $path })
+toast-info = Info
+toast-error = Galat
+workspace-new = baru
+key-unbound = tidak terikat
+
+## Notifikasi
+
+toast-config-error = Galat konfigurasi: { $error }
+error-config-preset = preset keymap '{ $preset }' tidak dikenal
+error-config-context = konteks tombol '{ $context }' tidak dikenal
+error-config-action = aksi '{ $action }' tidak dikenal
+error-config-key = tombol '{ $key }' tidak valid
+error-config-language = bahasa '{ $language }' tidak dikenal
+error-geoip-open = Basis data GeoIP { $path }: { $error }
+toast-session-saved = Sesi disimpan ke { $path }
+toast-session-save-failed = Gagal menyimpan sesi: { $error }
+toast-session-loaded = Sesi { $name } dimuat
+toast-session-empty = Sesi { $name } tidak memiliki ruang kerja
+error-session-name = '{ $name }' tidak bisa menjadi nama sesi: tanpa '/', '\' atau '..'
+toast-session-load-failed = Gagal memuat sesi: { $error }
+toast-report-written = Laporan ditulis ke { $path }
+toast-workspace-reset = Semua masukan dan hasil dikosongkan
+toast-exported = Diekspor ke { $path }
+toast-export-failed = Ekspor gagal: { $error }
+toast-unknown-command = Perintah tidak dikenal: { $command }
+
+## Jendela sembul
+
+interfaces-none = Tidak ada antarmuka IPv4 (Esc untuk menutup)
+interfaces-title = Antarmuka Lokal (Enter untuk memakai, Esc untuk batal)
+interfaces-failed = Gagal membaca daftar antarmuka: { $error }
+export-formats = Format Ekspor
+export-preview = Pratinjau (Enter untuk menyimpan, Esc untuk batal)
+export-format-ios-acl = Daftar akses Cisco IOS
+export-format-ios-route = Rute statis Cisco IOS
+export-format-ios-dhcp = Pool DHCP Cisco IOS
+export-format-nftables = nftables
+export-format-iptables = iptables
+export-format-linux-route = Rute Linux
+export-format-bind-zones = Zona balik BIND
+export-format-hosts = Berkas hosts
+export-format-terraform = Terraform aws_subnet
+export-format-ansible = Inventaris Ansible
+error-export-no-subnet = Hitung subnet sebelum mengekspor
+palette-title = Palet Perintah (ketik untuk menyaring, Enter untuk menjalankan, Esc untuk batal):
+palette-actions = Aksi
+session-save-title = Simpan Sesi Sebagai (Enter untuk menyimpan, Esc untuk batal):
+session-load-title = Muat Sesi (ketik untuk menyaring, Enter untuk memuat, Esc untuk batal):
+session-list = Sesi di { $path }
+
+## Kalkulator
+
+calc-enter-ip = Masukkan Alamat IP (Kiri/Kanan untuk memilih oktet):
+calc-enter-mask = Masukkan Subnet Mask atau /prefiks (Kiri/Kanan untuk memilih oktet):
+calc-enter-hosts = Masukkan Jumlah Host yang Dibutuhkan:
+calc-enter-subnets = Masukkan Jumlah Subnet yang Dibutuhkan:
+calc-input-help = Tekan '{ $ip }' untuk IP, '{ $mask }' untuk Subnet, '{ $hosts }' untuk Host Dibutuhkan, '{ $subnets }' untuk Subnet Dibutuhkan
+field-ip = IP
+field-subnet = Subnet
+field-hosts-needed = Host Dibutuhkan
+field-subnets-needed = Subnet Dibutuhkan
+field-cidr = CIDR
+field-mac = MAC
+field-prefix = Prefiks
+calc-zones-more = { $zones }, ... ({ $count } zona)
+calc-sizing = /{ $prefix } ({ $mask }), { $usable } terpakai - tekan '{ $key }' untuk memakai
+calc-subnetting = pinjam { $bits } bit -> /{ $prefix } ({ $mask }), { $subnets } subnet x { $hosts } host
+calc-dhcp-static = statis { $first }-{ $last },{" "}
+calc-dhcp = gateway { $gateway }, { $excluded }pool { $first }-{ $last } ({ $percent }% statis, { $shrink }/{ $grow } untuk mengatur)
+calc-cloud-hosts = { $hosts } ({ $cloud } mencadangkan { $reserved })
+calc-cloud-off = mati ({ $key } untuk AWS/Azure/GCP)
+cloud-network = jaringan
+cloud-vpc-router = router VPC
+cloud-default-gateway = gateway bawaan
+cloud-dns = DNS
+cloud-azure-dns = DNS Azure
+cloud-future-use = cadangan mendatang
+cloud-broadcast = broadcast
+result-network = Alamat Jaringan
+result-broadcast = Alamat Broadcast
+result-subnet-count = Jumlah Subnet
+result-host-count = Jumlah Host
+result-cloud = Cadangan Cloud
+result-class = Kelas IP
+result-scope = Cakupan
+result-reverse-zone = Zona Balik
+result-hosts-prefix = Prefiks Terkecil untuk Host
+result-subnetting = Subnetting
+result-dhcp = Cakupan DHCP
+result-location = Lokasi
+calc-results = Perhitungan Subnet
+calc-history = Riwayat
+calc-warnings = Peringatan
+suggestions-title = Tab untuk melengkapi
+
+help-membership = Keanggotaan
+help-overlaps = Tumpang Tindih
+help-tree = Pohon
+help-range = Rentang ke CIDR
+help-hosts = Daftar Host
+help-quiz = Kuis
+help-ipv6 = IPv6
+help-arithmetic = Hitung IP
+help-cleanup = Rapikan
+help-interfaces = Antarmuka
+help-export = Ekspor
+help-report = Laporan
+help-cheat-sheet = Contekan
+help-transition = Bentuk IPv6
+help-whois = Whois
+help-compare = Bandingkan
+help-explain = Penjelasan
+help-zoom = Perbesar
+help-swap = Tukar IP/Mask
+help-clear-field = Kosongkan Isian
+help-reset = Atur Ulang
+help-sweep = Ping Sweep
+help-next-subnet = Subnet Berikutnya
+help-prev-subnet = Subnet Sebelumnya
+help-next-workspace = Ruang Kerja Berikutnya
+help-new-workspace = Baru
+help-close-workspace = Tutup
+help-save = Simpan
+help-load = Muat
+help-undo = Batalkan
+help-redo = Ulangi
+help-palette = Palet
+help-quit = Keluar
+
+warning-cloud-prefix = Subnet { $cloud } harus antara /{ $shortest } dan /{ $longest }
+warning-inside = { $ip } berada di ruang { $scope } { $block } ({ $rfc })
+warning-overlaps = { $first }-{ $last } beririsan dengan ruang { $scope } { $block } ({ $rfc })
+
+class-a = Kelas A
+class-b = Kelas B
+class-c = Kelas C
+class-d = Kelas D (multicast)
+class-e = Kelas E (eksperimental)
+
+scope-this-network = Jaringan "ini"
+scope-private = Privat
+scope-shared-cgnat = Bersama (CGNAT)
+scope-loopback = Loopback
+scope-link-local = Link-local
+scope-protocol-assignments = Penetapan protokol IETF
+scope-documentation = Dokumentasi
+scope-relay-6to4 = Anycast relay 6to4
+scope-benchmarking = Benchmarking
+scope-multicast = Multicast
+scope-reserved = Dicadangkan
+scope-broadcast = Broadcast terbatas
+scope-public = Publik
+
+## Panel samping
+
+transition-empty = Hitung sebuah alamat untuk mengonversinya
+transition-title = Transisi IPv6 ({ $key } untuk menyembunyikan)
+rdap-waiting = Menanyakan ke registri...
+rdap-org = Organisasi
+rdap-network = Jaringan
+rdap-handle = Handle
+rdap-country = Negara
+rdap-asn = ASN
+rdap-allocated = Dialokasikan
+rdap-title = RDAP { $ip } ({ $key } untuk menyembunyikan)
+rdap-unsupported = Build ini tanpa dukungan RDAP; bangun ulang dengan --features rdap
+rdap-unreadable = Respons RDAP tidak terbaca: { $error }
+rdap-no-network = Tidak ada jaringan IP dalam respons RDAP
+error-rdap-no-address = Hitung sebuah alamat sebelum mencari pemiliknya
+error-rdap-not-public = { $ip } bukan alamat publik, jadi tidak ada registri yang memegangnya
+compare-title = Bandingkan ({ $key } untuk melepas)
+compare-pinned = Disematkan
+compare-current = Saat Ini
+compare-overlap = Tumpang Tindih
+compare-empty = Hitung subnet untuk membandingkannya dengan yang disematkan
+compare-identical = Blok yang sama
+compare-pinned-contains = Blok saat ini adalah { $share } dari { $block } yang disematkan
+compare-current-contains = Blok yang disematkan adalah { $share } dari { $block } saat ini
+compare-halves = Dua paruh terpisah dari { $block }
+compare-disjoint = Terpisah, tidak ada alamat yang sama
+error-compare-no-subnet = Hitung subnet sebelum menyematkannya
+explain-empty = Hitung subnet untuk melihat cara pengerjaannya
+explain-title-empty = Penjelasan ({ $back } untuk kembali)
+explain-title = Langkah { $step } dari { $steps }: { $title } ({ $back } untuk kembali)
+explain-steps = Langkah ({ $up }/{ $down })
+explain-network = Alamat Jaringan
+explain-broadcast = Alamat Broadcast
+explain-range = Rentang Host
+explain-hosts = Jumlah Host
+explain-subnets = Subnet dari Bit Pinjaman
+explain-first-host = Pertama
+explain-last-host = Terakhir
+explain-old-mask = Sebelum
+explain-new-mask = Sesudah
+explain-and-rule = AND-kan IP dengan mask: bit jaringan bernilai 1 hanya jika kedua bit bernilai 1, sehingga semua bit host menjadi 0.
+explain-octet-and = Oktet { $n }: { $ip } AND { $mask } = { $result }
+explain-or-rule = Balik mask untuk mendapatkan wildcard, lalu OR-kan dengan jaringan: setiap bit host menjadi 1.
+explain-octet-or = Oktet { $n }: { $network } OR { $wildcard } = { $result }
+explain-first = Host pertama = jaringan + 1 = { $first }
+explain-last = Host terakhir = broadcast - 1 = { $last }
+explain-range-rfc3021 = Sebuah /{ $prefix } tidak menyisihkan alamat jaringan atau broadcast (RFC 3021), jadi setiap alamat adalah host.
+explain-host-bits = /{ $prefix } menyisakan 32 - { $prefix } = { $bits } bit host.
+explain-addresses = 2^{ $bits } = { $addresses } alamat dalam blok.
+explain-usable = { $addresses } - 2 untuk jaringan dan broadcast = { $hosts } host yang dapat dipakai.
+explain-usable-rfc3021 = RFC 3021 menghitung semua { $hosts } alamat sebagai host yang dapat dipakai.
+explain-borrowed = Meminjam { $bits } bit host menggeser prefiks dari /{ $from } ke /{ $to }.
+explain-subnet-count = 2^{ $bits } = { $subnets } subnet, cukup untuk { $required } yang dibutuhkan.
+explain-classful-count = 2^{ $bits } = { $subnets } subnet dari jaringan classful /{ $class }.
+explain-subnet-hosts = Setiap subnet menyisakan { $bits } bit host untuk { $hosts } host yang dapat dipakai.
+explain-no-borrowed = Tidak ada bit host yang dipinjam. Isi Subnet Dibutuhkan untuk membagi blok.
+cheat-sheet-title = Contekan ({ $up }/{ $down } untuk menggulir, { $key } untuk menyembunyikan)
+binary-title-empty = Biner
+binary-title = Biner - prefiks /{ $prefix } ({ $shrink }/{ $grow } untuk mengubah ukuran)
+binary-ip = IP
+binary-mask = Mask
+binary-network = Jaringan
+binary-broadcast = Broadcast
+
+## Kolom tabel
+
+column-mask = Mask
+column-wildcard = Wildcard
+column-hosts = Host
+column-block = Blok
+column-relation = Relasi
+column-overlaps = Beririsan
+column-host = Host
+column-address = Alamat
+column-label = Label
+column-name = Nama
+column-status = Status
+column-rtt = RTT
+
+## Judul daftar
+
+filter-typing = {" "}- saring: /{ $query }_ (Enter untuk mempertahankan, Esc untuk menghapus)
+filter-applied = {" "}- saring: /{ $query }
+filter-hint = {" "}- { $key } untuk menyaring
+list-ignored = {" "}- diabaikan: { $entries }
+
+## Keanggotaan
+
+membership-enter-ip = Masukkan IP Kandidat:
+membership-enter-cidr = Masukkan Blok CIDR:
+membership-help = Tekan '{ $ip }' untuk IP, '{ $cidr }' untuk CIDR, { $back } untuk kembali
+membership-invalid-ip = Alamat IP tidak valid
+membership-invalid-cidr = CIDR tidak valid, contoh yang benar 10.0.0.0/22
+membership-outside = { $ip } TIDAK berada di dalam { $block }
+membership-host = host #{ $number }
+membership-network = alamat jaringan
+membership-broadcast = alamat broadcast
+membership-inside =
+    { $ip } berada di dalam { $block }
+    Offset: { $offset } dari { $size }
+    Posisi: { $position }
+membership-title = Cek Keanggotaan
+
+## Tumpang tindih
+
+overlap-load-file = Muat dari Berkas
+overlap-read-failed = Tidak dapat membaca { $path }: { $error }
+overlap-enter-list = Masukkan CIDR, satu per baris (Esc untuk menganalisis):
+overlap-enter-path = Masukkan path berkas (Enter untuk memuat):
+overlap-help = Tekan '{ $edit }' untuk Mengubah Daftar, '{ $load }' untuk Memuat Berkas, { $back } untuk kembali
+overlap-duplicate = duplikat
+overlap-contains = memuat
+overlap-title = { $blocks } blok, { $pairs } pasangan beririsan ({ $up }/{ $down } untuk menggulir)
+routes-imported = { $count } rute diimpor dari keluaran { $format }
+routes-imported-without-default = { $count } rute diimpor dari keluaran { $format }, tanpa rute default
+
+## Pohon
+
+tree-enter-parent = Masukkan Blok Induk (Enter untuk menyusun):
+tree-enter-children = Masukkan Blok Anak, satu per baris (Esc untuk menyusun):
+tree-help = Tekan '{ $parent }' untuk Induk, '{ $edit }' untuk Mengubah Anak, { $collapse } untuk Menciutkan, { $back } untuk kembali
+tree-invalid-parent = Blok induk tidak valid
+tree-children = Blok Anak
+tree-free = bebas { $free } dari { $size } ({ $percent }%)
+tree-title = Pohon Cakupan
+tree-outside = {" "}- di luar induk: { $blocks }
+usage-title = Pemakaian { $block } - { $percent }% terpakai (█ terpakai, ▒ sebagian, · bebas)
+usage-no-gaps = Tanpa celah: teralokasi penuh
+usage-gaps = Celah: { $gaps }
+
+## Rentang ke CIDR
+
+range-enter = Masukkan Rentang IP, misalnya 10.0.0.17 - 10.0.3.200:
+range-help = Tekan '{ $edit }' untuk Rentang, { $back } untuk kembali
+range-invalid = Rentang tidak valid, contoh yang benar 10.0.0.17 - 10.0.3.200
+range-title = { $blocks } blok mencakup { $addresses } alamat ({ $up }/{ $down } untuk menggulir)
+
+## IPv6
+
+ipv6-enter-address = Masukkan Alamat IPv6, misalnya 2001:db8::1 atau fe80::1%eth0:
+ipv6-help = Tekan '{ $edit }' untuk Alamat, { $back } untuk kembali
+ipv6-compressed = Ringkas
+ipv6-expanded = Lengkap
+ipv6-network = Jaringan
+ipv6-embedded = IPv4
+ipv6-zone = ID Zona
+ipv6-zone-unused = { $zone } (hanya bermakna pada link-local fe80::/10)
+ipv6-title-note = Bentuk IPv6 - { $note }
+ipv6-title = Bentuk IPv6 ('{ $key }' untuk ULA /48 acak)
+ipv6-ula-source = Dibuat dari { $source }
+ipv6-bad-prefix-length = '/{ $prefix }' bukan panjang prefiks
+ipv6-bad-address = '{ $address }' bukan alamat IPv6
+ipv6-empty-zone = ID zona setelah '%' kosong
+ipv6-bad-zone = ID zona tidak boleh berisi '{ $character }'
+ipv6-bad-prefix = '{ $prefix }' bukan prefiks IPv6
+ipv6-kind-mapped = IPv4 terpetakan
+ipv6-kind-6to4 = 6to4
+ipv6-kind-nat64 = NAT64
+ipv6-kind-teredo = Teredo
+ula-source-mac = SHA-1 dari waktu dan EUI-64 { $interface }
+ula-source-random = pembangkit bilangan acak OS
+ula-failed = Gagal membuat ULA: { $error }
+eui64-enter-mac = Masukkan Alamat MAC, misalnya 00:1a:2b:3c:4d:5e:
+eui64-enter-prefix = Masukkan Prefiks /64, misalnya 2001:db8:1:2::/64:
+eui64-help = EUI-64: Tekan '{ $mac }' untuk MAC, '{ $prefix }' untuk Prefiks
+eui64-step-mac = 1. MAC:
+eui64-step-insert = 2. Sisipkan ff:fe:
+eui64-step-flip = 3. Balik bit U/L:
+eui64-step-append = 4. Tambahkan ke prefiks:
+eui64-title = Alamat EUI-64
+eui64-needs-64 = EUI-64 butuh prefiks /64, bukan /{ $length }
+eui64-bad-mac = '{ $mac }' bukan alamat MAC
+
+## Aritmetika IP
+
+arith-enter = Masukkan +N atau -N di dalam subnet, IP + N / IP - N, atau IP - IP:
+arith-help = Tekan '{ $edit }' untuk Ekspresi, { $back } untuk kembali
+arith-title-block = Hasil (offset di dalam { $block })
+arith-title = Hasil
+arith-usage = Diharapkan offset seperti +130 atau -1, IP + N / IP - N, atau IP - IP
+arith-no-subnet = Hitung subnet dulu untuk memakai offset di dalamnya
+arith-out-of-space = { $expression } keluar dari ruang alamat IPv4
+arith-inside = { $address } berada di dalam { $block }
+arith-outside = { $address } berada di luar { $block }
+arith-past-broadcast = +{ $n } melewati alamat broadcast { $broadcast }; { $block } berakhir di +{ $last }
+arith-before-network = -{ $n } sebelum alamat jaringan { $network }; { $block } dimulai di -{ $last }
+arith-role-network = alamat jaringan
+arith-role-broadcast = alamat broadcast
+arith-role-host = host { $number } dari { $count }
+arith-address = Alamat: { $address }
+arith-from-network = +{ $n } dari jaringan adalah { $role }
+arith-from-broadcast = -{ $n } dari broadcast adalah { $role }
+arith-distance = Jarak: { $gap } alamat dari { $start } ke { $end }
+arith-between = { $between } alamat di antaranya, { $inclusive } termasuk kedua ujung
+arith-spanned = Tepat dicakup oleh { $block }
+arith-not-spanned = Tidak ada satu prefiks yang mencakup tepat; butuh { $count } blok
+arith-covering = Blok terkecil yang memuat keduanya: { $block } ({ $size } alamat)
+
+## Rapikan
+
+cleanup-enter = Tempel CIDR, pemisah apa saja (Esc untuk merapikan):
+cleanup-help = Tekan '{ $edit }' untuk Mengubah Daftar, { $back } untuk kembali
+cleanup-title = { $entries } entri, { $unique } unik, { $merged } tergabung, { $addresses } alamat ({ $up }/{ $down } untuk menggulir)
+
+## Host
+
+hosts-title = Host { $selected } dari { $count } - halaman { $page } dari { $pages }
+hosts-marked = {" "}- { $count } ditandai
+hosts-enter-jump = Lompat ke nomor host:
+hosts-enter-search = Cari:
+hosts-enter-port = Port TCP yang dicek pada host bertanda (semua jika tidak ada):
+hosts-enter-label = Label untuk { $host } (kosongkan untuk menghapus):
+hosts-help = '{ $jump }' untuk Lompat, '{ $search }' untuk Cari, '{ $next }' untuk Hasil Berikutnya, { $mark } untuk Menandai, '{ $port }' untuk Cek Port, '{ $label }' untuk Memberi Label, '{ $names }' untuk Nama DNS, { $page_up }/{ $page_down }, { $back } untuk kembali
+hosts-out-of-range = Nomor host harus 1..{ $count }
+hosts-no-match = Tidak ada host yang cocok dengan "{ $query }"
+hosts-no-match-within = Tidak ada host dalam { $limit } berikutnya yang cocok dengan "{ $query }"
+hosts-enter-number = Masukkan nomor host
+hosts-enter-port-number = Masukkan nomor port 1-65535
+
+## Kuis
+
+quiz-question = Pertanyaan
+quiz-answer = Jawaban Anda (Enter untuk mengirim, Esc untuk kembali)
+quiz-correct = Benar! Tekan { $key } untuk pertanyaan berikutnya
+quiz-wrong =
+    Salah, jawabannya { $answer }
+    Tekan { $key } untuk pertanyaan berikutnya
+quiz-result = Hasil
+quiz-score =
+    Skor: { $correct }/{ $asked }
+    Beruntun: { $streak }
+    Beruntun Terbaik: { $best }
+quiz-title = Kuis Subnetting
+quiz-network = Apa alamat jaringan dari { $block }?
+quiz-broadcast = Apa alamat broadcast dari { $block }?
+quiz-first-host = Apa host pertama yang dapat dipakai dari { $block }?
+quiz-last-host = Apa host terakhir yang dapat dipakai dari { $block }?
+quiz-usable-hosts = Berapa host yang dapat dipakai pada { $block }?
+quiz-mask = Apa subnet mask bertitik untuk /{ $prefix }?
+
+## Mode baris
+
+inline-intro = Kalkulator subnet, mode baris. Masukkan alamat kosong untuk keluar.
+inline-ip = Alamat IP, boleh dengan /prefiks:
+inline-mask = Subnet mask atau panjang prefiks:
+inline-hosts = Host dibutuhkan, atau Enter untuk melewati:
+inline-subnets = Subnet dibutuhkan, atau Enter untuk melewati:
+inline-bad-ip = { $input } bukan alamat IPv4
+inline-bad-mask = { $input } bukan subnet mask atau panjang prefiks
+inline-warning = Peringatan: { $warning }
+inline-range = { $first } sampai { $last }
+inline-sizing = /{ $prefix } ({ $mask }), { $usable } terpakai
+inline-dhcp = gateway { $gateway }, { $excluded }pool { $first }-{ $last } ({ $percent }% statis)
+result-host-range = Rentang Host
+
+## Laporan
+
+report-title = Laporan Perhitungan Subnet
+report-generated = Dibuat { $time } UTC
+report-inputs = Masukan
+report-results = Hasil
+report-ip = Alamat IP
+report-mask = Subnet Mask
+report-wildcard = Wildcard Mask
+report-cloud = { $cloud }: { $reserved } ({ $usable } terpakai)
+report-labels = Label Host
+report-binary = Biner (| menandai batas /{ $prefix })
+error-report-no-subnet = Hitung subnet sebelum menulis laporan
+error-report-write = Gagal menulis laporan: { $error }
+
+## Ping sweep
+
+error-rdns-no-nameserver = Tidak ada nameserver untuk DNS balik: tidak ada di /etc/resolv.conf dan tidak ada `nameserver` di config.toml
+error-sweep-no-subnet = Hitung subnet sebelum melakukan sweep
+error-sweep-too-large = { $block } terlalu besar untuk di-sweep, batasnya { $limit } host
+error-probe-too-many = Terlalu banyak host untuk diperiksa, batasnya { $limit }
+sweep-progress = Kemajuan
+sweep-rate = { $finished } / { $total } - { $rate } host/dtk
+sweep-probe-port = Port { $port }
+sweep-up = aktif
+sweep-open = terbuka
+sweep-closed = tertutup
+sweep-no-answer = tanpa jawaban
+sweep-down = mati
+sweep-kind-scan = pemindaian
+sweep-kind-sweep = sweep
+sweep-running = berjalan
+sweep-cancelled = dibatalkan
+sweep-done = selesai
+sweep-cancel = batal
+sweep-go-back = kembali
+sweep-title = { $kind } { $probe } { $state }: { $answered } { $answer }, { $finished } dari { $total } diperiksa ({ $switch } untuk ganti probe, { $names } untuk nama DNS, { $up }/{ $down } untuk menggulir, { $back_key } untuk { $back })
+
+## Deskripsi aksi pada palet perintah
+
+action-quit = Keluar dari aplikasi
+action-command-line = Jalankan aksi berdasarkan nama
+action-command-palette = Cari semua aksi
+action-new-workspace = Buka tab ruang kerja baru
+action-close-workspace = Tutup tab ruang kerja ini
+action-next-workspace = Pindah ke ruang kerja berikutnya
+action-prev-workspace = Pindah ke ruang kerja sebelumnya
+action-save-session = Simpan semua ruang kerja ke berkas sesi
+action-load-session = Muat sesi yang tersimpan
+action-undo = Batalkan suntingan terakhir pada isian aktif
+action-redo = Ulangi suntingan yang terakhir dibatalkan
+action-up = Naik
+action-down = Turun
+action-page-up = Naik satu halaman
+action-page-down = Turun satu halaman
+action-home = Ke entri pertama
+action-end = Ke entri terakhir
+action-back = Kembali ke kalkulator
+action-toggle = Ciutkan blok pohon atau tandai host
+action-submit = Kirim jawaban atau minta pertanyaan berikutnya
+action-focus-ip = Ubah alamat IP
+action-focus-mask = Ubah subnet mask
+action-focus-hosts = Ubah jumlah host yang dibutuhkan
+action-focus-subnets = Ubah jumlah subnet yang dibutuhkan
+action-calculate = Hitung subnet
+action-use-hosts-prefix = Pakai prefiks yang sesuai dengan jumlah host
+action-next-subnet = Maju ke subnet berikutnya dengan ukuran sama
+action-prev-subnet = Mundur ke subnet sebelumnya dengan ukuran sama
+action-grow-prefix = Perpanjang prefiks satu bit
+action-shrink-prefix = Perpendek prefiks satu bit
+action-open-membership = Periksa apakah sebuah IP berada di dalam blok CIDR
+action-open-overlap = Cari blok CIDR yang beririsan
+action-open-tree = Tampilkan pohon cakupan blok CIDR
+action-open-range = Ubah rentang IP menjadi blok CIDR
+action-open-hosts = Daftar host dalam subnet
+action-open-quiz = Latihan subnetting
+action-open-ipv6 = Ringkas atau lengkapi alamat IPv6, atau turunkan dengan EUI-64
+action-open-arithmetic = Offset di dalam subnet, tambah ke alamat atau ukur jarak dua alamat
+action-open-cleanup = Urutkan, hapus duplikat dan gabungkan daftar CIDR
+action-generate-ula = Buat IPv6 unique local /48 acak (RFC 4193)
+action-open-sweep = Ping sweep subnet
+action-switch-probe = Ganti sweep antara ICMP dan TCP
+action-port-scan = Periksa port TCP pada host bertanda
+action-label-host = Beri nama atau catatan pada host terpilih
+action-resolve-names = Tampilkan nama DNS balik dari host yang terdaftar
+action-shrink-static-range = Berikan lebih banyak cakupan DHCP ke pool
+action-grow-static-range = Sisihkan lebih banyak cakupan DHCP untuk alamat statis
+action-cycle-cloud = Perhitungkan alamat cadangan AWS, Azure atau GCP
+action-toggle-cheat-sheet = Tampilkan atau sembunyikan contekan netmask
+action-toggle-transition = Tampilkan alamat sebagai IPv4-mapped, 6to4 dan NAT64
+action-clear-field = Kosongkan isian yang sedang diketik
+action-reset = Kosongkan semua masukan dan hasil di ruang kerja
+action-swap = Tukar alamat IP dan subnet mask
+action-rdap = Cari pemegang alamat publik lewat RDAP
+action-pin-compare = Sematkan perhitungan untuk dibandingkan dengan yang lain
+action-explain = Tampilkan penyelesaian langkah demi langkah
+action-toggle-zoom = Perbesar panel utama memenuhi layar, atau pulihkan
+action-toggle-debug-log = Tampilkan atau sembunyikan baris log terbaru
+action-pick-interface = Isi dari antarmuka jaringan lokal
+action-export = Ekspor subnet sebagai cuplikan ACL, rute atau infrastructure-as-code
+action-export-report = Tulis laporan teks dari perhitungan
+action-focus-cidr = Ubah blok CIDR
+action-focus-mac = Ubah alamat MAC
+action-edit-list = Ubah daftar blok
+action-load-file = Muat blok dari berkas
+action-focus-parent = Ubah blok induk
+action-edit-range = Ubah rentang IP
+action-jump-to-host = Lompat ke nomor host
+action-search = Cari atau saring daftar
+action-next-match = Ke hasil pencarian berikutnya
@@ -0,0 +1,1752 @@
+use std::{
+    env,
+    net::{IpAddr, Ipv4Addr},
+};
+
+use ratatui::layout::Rect;
+
+use crate::{
+    arith::Arithmetic,
+    cidr::{self, Cidr, Subnetting},
+    classify::{self, IpClass, Scope},
+    cleanup::CleanupTool,
+    cloud::Cloud,
+    complete::{self, Suggestion},
+    config::{self, Config},
+    dhcp::{self, DhcpScope},
+    event::Event,
+    explain,
+    export::{self, ExportPicker, SubnetInfo},
+    geoip::GeoIp,
+    hosts::{HostList, HostPrompt, Labels},
+    i18n::{self, tr},
+    input::Input,
+    interfaces::InterfacePicker,
+    ipv6::{Ipv6Input, Ipv6Tool},
+    key::{KeyCode, KeyEvent, KeyModifiers},
+    keymap::{Action, Context, Keymap},
+    membership::{MembershipCheck, MembershipInput},
+    octet::{self, Append, OctetCursor},
+    overlap::{OverlapCheck, OverlapInput},
+    palette::Palette,
+    quiz::Quiz,
+    range::RangeConverter,
+    rdap::RdapLookup,
+    rdns::{self, Resolver},
+    report,
+    routes::{self, Import},
+    search::ListFilter,
+    session::{self, PromptKind, Session, SessionPrompt, WorkspaceState},
+    sweep::{self, Probe, Sweep},
+    toast::Toasts,
+    tree::{TreeInput, TreeView},
+    ui, ula,
+};
+
+pub enum InputMode {
+    IP,
+    Subnet,
+    Hosts,
+    Subnets,
+    NoTyping,
+}
+
+#[derive(Clone, Copy)]
+pub enum Screen {
+    Calculator,
+    Membership,
+    Overlap,
+    Tree,
+    Range,
+    Hosts,
+    Quiz,
+    Sweep,
+    Ipv6,
+    Arithmetic,
+    Cleanup,
+    Explain,
+}
+
+/// A reference pane shown beside the calculator results.
+#[derive(Clone, Copy, PartialEq)]
+pub enum SidePane {
+    CheatSheet,
+    Transition,
+    Rdap,
+    Compare,
+}
+
+/// What kind of text the focused field takes.
+#[derive(Clone, Copy, PartialEq)]
+pub enum FieldKind {
+    /// Addresses, masks and numbers; letters never belong in them.
+    Address,
+    /// A dotted-quad address, edited octet by octet.
+    Quad,
+    /// A dotted-quad mask, or a `/` prefix length standing for one.
+    Mask,
+    Text,
+    Multiline,
+}
+
+const HISTORY_LIMIT: usize = 50;
+
+pub struct App {
+    pub workspaces: Vec<Workspace>,
+    pub active: usize,
+    pub should_quit: bool,
+    pub session_prompt: Option<SessionPrompt>,
+    pub palette: Option<Palette>,
+    pub interfaces: Option<InterfacePicker>,
+    pub export: Option<ExportPicker>,
+    pub command_line: Option<Input>,
+    pub toasts: Toasts,
+    pub debug_log: bool,
+    /// Styles with attributes and ASCII borders only, for terminals or
+    /// eyes where the colors don't work.
+    pub no_color: bool,
+    pub keymap: Keymap,
+    pub geoip: GeoIp,
+    pub nameserver: Option<IpAddr>,
+    /// The terminal's size, as of the last resize.
+    pub size: Rect,
+}
+
+impl App {
+    pub fn new() -> Self {
+        // https://no-color.org: set and not empty.
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        match config::load() {
+            Ok(config) => Self::with_config(config, no_color, Vec::new()),
+            Err(err) => Self::with_config(Config::default(), no_color, vec![err]),
+        }
+    }
+
+    /// The app as `config` sets it up, with `errors` met reading the
+    /// config shown as toasts.
+    fn with_config(config: Config, no_color: bool, mut errors: Vec<String>) -> Self {
+        errors.extend(i18n::init(config.language.as_deref()).err());
+        let (keymap, keymap_errors) = config.keymap.build();
+        let (geoip, geoip_errors) = GeoIp::open(&config.geoip);
+        errors.extend(keymap_errors);
+        errors.extend(geoip_errors);
+        let mut toasts = Toasts::new();
+        for err in errors {
+            toasts.error(tr!("toast-config-error", error = err));
+        }
+        Self {
+            workspaces: vec![Workspace::new()],
+            active: 0,
+            should_quit: false,
+            session_prompt: None,
+            palette: None,
+            interfaces: None,
+            export: None,
+            command_line: None,
+            toasts,
+            debug_log: false,
+            no_color: no_color || config.no_color,
+            keymap,
+            geoip,
+            nameserver: config.nameserver,
+            size: Rect::default(),
+        }
+    }
+
+    /// Sizes host pages to the rows the table has room for, which the
+    /// size, zoom and debug log all change.
+    fn fit_pages(&mut self) {
+        for ws in &mut self.workspaces {
+            if let Some(hosts) = &mut ws.hosts {
+                hosts.page_size = ui::hosts_page_size(self.size, self.debug_log, ws.zoomed);
+            }
+        }
+    }
+
+    pub fn workspace(&self) -> &Workspace {
+        &self.workspaces[self.active]
+    }
+
+    fn open_workspace(&mut self) {
+        self.workspaces.push(Workspace::new());
+        self.active = self.workspaces.len() - 1;
+    }
+
+    fn close_workspace(&mut self) {
+        if self.workspaces.len() > 1 {
+            self.workspaces.remove(self.active);
+            self.active = self.active.min(self.workspaces.len() - 1);
+        }
+    }
+
+    fn cycle_workspace(&mut self, forward: bool) {
+        let count = self.workspaces.len();
+        self.active = if forward {
+            (self.active + 1) % count
+        } else {
+            (self.active + count - 1) % count
+        };
+    }
+
+    /// Sends pasted text to the open prompt or the focused field.
+    fn paste(&mut self, text: &str) {
+        if let Some(prompt) = &mut self.session_prompt {
+            prompt.name.push_str(text.trim());
+        } else if let Some(palette) = &mut self.palette {
+            palette.query.push_str(text.trim());
+        } else if let Some(line) = &mut self.command_line {
+            line.insert_str(text.trim());
+        } else if let Some(import) = self.workspaces[self.active].paste(text) {
+            self.toasts.info(import.summary());
+        }
+    }
+
+    /// Collects results from background work, once per tick.
+    fn tick(&mut self) {
+        self.toasts.tick();
+        for ws in &mut self.workspaces {
+            if let Some(sweep) = &mut ws.sweep {
+                sweep.poll();
+            }
+            if let Some(lookup) = &mut ws.rdap {
+                lookup.poll();
+            }
+            ws.resolve_listed();
+        }
+    }
+
+    fn save_session(&mut self, name: &str) {
+        let session = Session {
+            active: self.active,
+            workspaces: self.workspaces.iter().map(Workspace::snapshot).collect(),
+        };
+        match session::save(name, &session) {
+            Ok(path) => self
+                .toasts
+                .info(tr!("toast-session-saved", path = path.display())),
+            Err(err) => self
+                .toasts
+                .error(tr!("toast-session-save-failed", error = err)),
+        }
+    }
+
+    fn load_session(&mut self, name: &str) {
+        match session::load(name) {
+            Ok(session) if !session.workspaces.is_empty() => {
+                self.workspaces = session
+                    .workspaces
+                    .into_iter()
+                    .map(Workspace::restore)
+                    .collect();
+                self.active = session.active.min(self.workspaces.len() - 1);
+                self.toasts.info(tr!("toast-session-loaded", name = name));
+            }
+            Ok(_) => self.toasts.error(tr!("toast-session-empty", name = name)),
+            Err(err) => self
+                .toasts
+                .error(tr!("toast-session-load-failed", error = err)),
+        }
+    }
+}
+
+/// An independent set of inputs, results and history, shown as a tab.
+pub struct Workspace {
+    pub screen: Screen,
+    pub ip_input: Input,
+    pub subnet_input: Input,
+    pub hosts_input: Input,
+    pub subnets_input: Input,
+    pub input_mode: InputMode,
+    pub ip_address: Option<Ipv4Addr>,
+    pub subnet_mask: Option<Ipv4Addr>,
+    pub network_address: Option<Ipv4Addr>,
+    pub broadcast_address: Option<Ipv4Addr>,
+    pub subnet_count: Option<u64>,
+    pub host_count: Option<u64>,
+    pub prefix_length: Option<u32>,
+    pub ip_class: Option<IpClass>,
+    pub scope: Option<(Scope, Option<&'static str>)>,
+    pub warnings: Vec<String>,
+    pub reverse_zones: Vec<String>,
+    pub hosts_prefix: Option<u32>,
+    pub subnetting: Option<Subnetting>,
+    pub dhcp: Option<DhcpScope>,
+    pub dhcp_static_percent: u32,
+    pub cloud: Option<Cloud>,
+    pub side_pane: Option<SidePane>,
+    /// The block the compare pane holds the current results against.
+    pub pinned: Option<Cidr>,
+    /// The screen's main pane fills the workspace.
+    pub zoomed: bool,
+    /// First cheat sheet row shown once scrolled by hand; `None` follows
+    /// the current prefix.
+    pub cheat_scroll: Option<usize>,
+    /// The step of the worked solution the explain screen is on.
+    pub explain_step: usize,
+    pub membership: MembershipCheck,
+    pub overlap: OverlapCheck,
+    pub tree: TreeView,
+    pub range: RangeConverter,
+    pub ipv6: Ipv6Tool,
+    pub arithmetic: Arithmetic,
+    pub cleanup: CleanupTool,
+    pub hosts: Option<HostList>,
+    pub labels: Labels,
+    pub quiz: Option<Quiz>,
+    pub sweep: Option<Sweep>,
+    pub rdap: Option<RdapLookup>,
+    /// Reverse DNS names for the listed hosts, while turned on.
+    pub names: Option<Resolver>,
+    pub history: Vec<(Ipv4Addr, Ipv4Addr)>,
+    pub suggestion: usize,
+    pub octet: Option<OctetCursor>,
+}
+
+impl Workspace {
+    pub fn new() -> Self {
+        Self {
+            screen: Screen::Calculator,
+            ip_input: Input::default(),
+            subnet_input: Input::default(),
+            hosts_input: Input::default(),
+            subnets_input: Input::default(),
+            input_mode: InputMode::NoTyping,
+            ip_address: None,
+            subnet_mask: None,
+            network_address: None,
+            broadcast_address: None,
+            subnet_count: None,
+            host_count: None,
+            prefix_length: None,
+            ip_class: None,
+            scope: None,
+            warnings: Vec::new(),
+            reverse_zones: Vec::new(),
+            hosts_prefix: None,
+            subnetting: None,
+            dhcp: None,
+            dhcp_static_percent: dhcp::DEFAULT_STATIC_PERCENT,
+            cloud: None,
+            side_pane: None,
+            pinned: None,
+            zoomed: false,
+            cheat_scroll: None,
+            explain_step: 0,
+            membership: MembershipCheck::new(),
+            overlap: OverlapCheck::new(),
+            tree: TreeView::new(),
+            range: RangeConverter::new(),
+            ipv6: Ipv6Tool::new(),
+            arithmetic: Arithmetic::new(),
+            cleanup: CleanupTool::new(),
+            hosts: None,
+            labels: Labels::new(),
+            quiz: None,
+            sweep: None,
+            rdap: None,
+            names: None,
+            history: Vec::new(),
+            suggestion: 0,
+            octet: None,
+        }
+    }
+
+    fn calculate_subnet(&mut self) {
+        let length = self.subnet_input.strip_prefix('/').map(str::parse::<u32>);
+        if let Some(Ok(prefix @ 0..=32)) = length {
+            self.subnet_input
+                .set(Ipv4Addr::from(cidr::prefix_mask(prefix)).to_string());
+        }
+        if let (Ok(ip), Ok(subnet)) = (
+            self.ip_input.parse::<Ipv4Addr>(),
+            self.subnet_input.parse::<Ipv4Addr>(),
+        ) {
+            self.apply(ip, subnet);
+            self.history.retain(|&entry| entry != (ip, subnet));
+            self.history.insert(0, (ip, subnet));
+            self.history.truncate(HISTORY_LIMIT);
+        } else {
+            tracing::debug!(
+                ip = %self.ip_input.as_str(),
+                mask = %self.subnet_input.as_str(),
+                "calculator inputs don't parse"
+            );
+        }
+    }
+
+    /// Calculates from every calculator field at once, for inputs filled
+    /// in without going through the keys.
+    pub fn calculate(&mut self) {
+        self.calculate_subnet();
+        self.size_for_hosts();
+    }
+
+    fn snapshot(&self) -> WorkspaceState {
+        WorkspaceState {
+            ip_input: self.ip_input.to_string(),
+            subnet_input: self.subnet_input.to_string(),
+            hosts_input: self.hosts_input.to_string(),
+            subnets_input: self.subnets_input.to_string(),
+            history: self.history.clone(),
+            membership_ip: self.membership.ip_input.to_string(),
+            membership_cidr: self.membership.cidr_input.to_string(),
+            overlap_list: self.overlap.list_input.to_string(),
+            overlap_path: self.overlap.path_input.to_string(),
+            tree_parent: self.tree.parent_input.to_string(),
+            tree_children: self.tree.children_input.to_string(),
+            range_input: self.range.input.to_string(),
+            ipv6_input: self.ipv6.input.to_string(),
+            ipv6_mac: self.ipv6.mac_input.to_string(),
+            ipv6_prefix: self.ipv6.prefix_input.to_string(),
+            arithmetic_input: self.arithmetic.input.to_string(),
+            cleanup_list: self.cleanup.list_input.to_string(),
+            dhcp_static_percent: Some(self.dhcp_static_percent),
+            cloud: self.cloud,
+            labels: self.labels.clone(),
+        }
+    }
+
+    /// Rebuilds a workspace from saved inputs, recalculating every result.
+    fn restore(state: WorkspaceState) -> Self {
+        let mut ws = Self::new();
+        if let Some(percent) = state.dhcp_static_percent {
+            ws.dhcp_static_percent = percent;
+        }
+        ws.cloud = state.cloud;
+        ws.labels = state.labels;
+        ws.ip_input = state.ip_input.into();
+        ws.subnet_input = state.subnet_input.into();
+        ws.hosts_input = state.hosts_input.into();
+        ws.subnets_input = state.subnets_input.into();
+        ws.calculate();
+        ws.history = state.history;
+
+        ws.membership.ip_input = state.membership_ip.into();
+        ws.membership.cidr_input = state.membership_cidr.into();
+        if !ws.membership.ip_input.is_empty() && !ws.membership.cidr_input.is_empty() {
+            ws.membership.check();
+        }
+        ws.overlap.list_input = state.overlap_list.into();
+        ws.overlap.path_input = state.overlap_path.into();
+        ws.overlap.analyze();
+        ws.tree.parent_input = state.tree_parent.into();
+        ws.tree.children_input = state.tree_children.into();
+        if !ws.tree.parent_input.is_empty() {
+            ws.tree.build();
+        }
+        ws.range.input = state.range_input.into();
+        if !ws.range.input.is_empty() {
+            ws.range.convert();
+        }
+        ws.ipv6.input = state.ipv6_input.into();
+        if !ws.ipv6.input.is_empty() {
+            ws.ipv6.convert();
+        }
+        ws.ipv6.mac_input = state.ipv6_mac.into();
+        ws.ipv6.prefix_input = state.ipv6_prefix.into();
+        ws.ipv6.derive();
+        ws.arithmetic.input = state.arithmetic_input.into();
+        if !ws.arithmetic.input.is_empty() {
+            ws.arithmetic.evaluate(ws.block());
+        }
+        ws.cleanup.list_input = state.cleanup_list.into();
+        ws.cleanup.clean();
+        ws
+    }
+
+    /// The calculated block, once there is one.
+    pub fn block(&self) -> Option<Cidr> {
+        self.network_address
+            .zip(self.prefix_length)
+            .map(|(network, prefix)| Cidr::new(network, prefix))
+    }
+
+    /// Short label for the workspace tab.
+    pub fn label(&self) -> String {
+        match (self.network_address, self.prefix_length) {
+            (Some(network), Some(prefix)) => format!("{}/{}", network, prefix),
+            (Some(network), None) => network.to_string(),
+            _ => tr!("workspace-new"),
+        }
+    }
+
+    /// The field receiving keystrokes and the kind of text it takes.
+    fn active_input(&mut self) -> Option<(&mut Input, FieldKind)> {
+        if self.filter().is_some_and(|filter| filter.typing) {
+            return self
+                .filter_mut()
+                .map(|filter| (&mut filter.input, FieldKind::Text));
+        }
+        match self.screen {
+            Screen::Calculator => match self.input_mode {
+                InputMode::IP => Some((&mut self.ip_input, FieldKind::Quad)),
+                InputMode::Subnet => Some((&mut self.subnet_input, FieldKind::Mask)),
+                InputMode::Hosts => Some((&mut self.hosts_input, FieldKind::Address)),
+                InputMode::Subnets => Some((&mut self.subnets_input, FieldKind::Address)),
+                InputMode::NoTyping => None,
+            },
+            Screen::Membership => match self.membership.input_mode {
+                MembershipInput::Ip => Some((&mut self.membership.ip_input, FieldKind::Quad)),
+                MembershipInput::Cidr => {
+                    Some((&mut self.membership.cidr_input, FieldKind::Address))
+                }
+                MembershipInput::NoTyping => None,
+            },
+            Screen::Overlap => match self.overlap.input_mode {
+                OverlapInput::List => Some((&mut self.overlap.list_input, FieldKind::Multiline)),
+                OverlapInput::Path => Some((&mut self.overlap.path_input, FieldKind::Text)),
+                OverlapInput::NoTyping => None,
+            },
+            Screen::Tree => match self.tree.input_mode {
+                TreeInput::Parent => Some((&mut self.tree.parent_input, FieldKind::Address)),
+                TreeInput::Children => Some((&mut self.tree.children_input, FieldKind::Multiline)),
+                TreeInput::NoTyping => None,
+            },
+            Screen::Range => self
+                .range
+                .typing
+                .then_some((&mut self.range.input, FieldKind::Address)),
+            Screen::Hosts => self.hosts.as_mut().and_then(|h| match h.prompt {
+                // Searches match labels as well as addresses.
+                Some(HostPrompt::Search | HostPrompt::Label) => {
+                    Some((&mut h.input, FieldKind::Text))
+                }
+                Some(HostPrompt::Jump | HostPrompt::Port) => {
+                    Some((&mut h.input, FieldKind::Address))
+                }
+                None => None,
+            }),
+            Screen::Quiz => self
+                .quiz
+                .as_mut()
+                .filter(|q| q.graded.is_none())
+                .map(|q| (&mut q.answer_input, FieldKind::Address)),
+            Screen::Sweep => None,
+            Screen::Ipv6 => match self.ipv6.input_mode {
+                Ipv6Input::Address => Some((&mut self.ipv6.input, FieldKind::Text)),
+                Ipv6Input::Mac => Some((&mut self.ipv6.mac_input, FieldKind::Text)),
+                Ipv6Input::Prefix => Some((&mut self.ipv6.prefix_input, FieldKind::Text)),
+                Ipv6Input::NoTyping => None,
+            },
+            Screen::Arithmetic => self
+                .arithmetic
+                .typing
+                .then_some((&mut self.arithmetic.input, FieldKind::Address)),
+            Screen::Cleanup => self
+                .cleanup
+                .typing
+                .then_some((&mut self.cleanup.list_input, FieldKind::Multiline)),
+            Screen::Explain => None,
+        }
+    }
+
+    /// Inserts pasted text into the active field as one undoable edit.
+    /// Route table output pasted into a list becomes its destinations,
+    /// returned so the import can be reported.
+    fn paste(&mut self, text: &str) -> Option<Import> {
+        let (input, kind) = self.active_input()?;
+        if kind != FieldKind::Multiline {
+            input.insert_str(text.lines().next().unwrap_or_default().trim());
+            return None;
+        }
+        let Some(import) = routes::import(text) else {
+            input.insert_str(text);
+            return None;
+        };
+        let separator = if input.is_empty() || input.ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        input.insert_str(&format!("{}{}", separator, import.to_list()));
+        Some(import)
+    }
+
+    pub fn context(&self) -> Context {
+        match self.screen {
+            Screen::Calculator => Context::Calculator,
+            Screen::Membership => Context::Membership,
+            Screen::Overlap => Context::Overlap,
+            Screen::Tree => Context::Tree,
+            Screen::Range => Context::Range,
+            Screen::Hosts => Context::Hosts,
+            Screen::Quiz => Context::Quiz,
+            Screen::Sweep => Context::Sweep,
+            Screen::Ipv6 => Context::Ipv6,
+            Screen::Arithmetic => Context::Arithmetic,
+            Screen::Cleanup => Context::Cleanup,
+            Screen::Explain => Context::Explain,
+        }
+    }
+
+    /// Completions for the focused calculator field: recent addresses for
+    /// the IP, common masks for the subnet.
+    pub fn suggestions(&self) -> Vec<Suggestion> {
+        match (self.screen, &self.input_mode) {
+            (Screen::Calculator, InputMode::IP) => {
+                complete::addresses(&self.ip_input, self.history.iter().copied())
+            }
+            (Screen::Calculator, InputMode::Subnet) => complete::masks(&self.subnet_input),
+            _ => Vec::new(),
+        }
+    }
+
+    fn move_suggestion(&mut self, delta: isize) {
+        let count = self.suggestions().len();
+        self.suggestion = self
+            .suggestion
+            .saturating_add_signed(delta)
+            .min(count.saturating_sub(1));
+    }
+
+    fn accept_suggestion(&mut self) {
+        let Some(suggestion) = self.suggestions().into_iter().nth(self.suggestion) else {
+            return;
+        };
+        if let Some((input, _)) = self.active_input() {
+            input.set(suggestion.value);
+        }
+        self.suggestion = 0;
+        self.octet = None;
+    }
+
+    /// The `/` filter over the current screen's result list, if it has one.
+    fn filter(&self) -> Option<&ListFilter> {
+        match self.screen {
+            Screen::Overlap => Some(&self.overlap.filter),
+            Screen::Range => Some(&self.range.filter),
+            Screen::Cleanup => Some(&self.cleanup.filter),
+            Screen::Sweep => self.sweep.as_ref().map(|sweep| &sweep.filter),
+            _ => None,
+        }
+    }
+
+    fn filter_mut(&mut self) -> Option<&mut ListFilter> {
+        match self.screen {
+            Screen::Overlap => Some(&mut self.overlap.filter),
+            Screen::Range => Some(&mut self.range.filter),
+            Screen::Cleanup => Some(&mut self.cleanup.filter),
+            Screen::Sweep => self.sweep.as_mut().map(|sweep| &mut sweep.filter),
+            _ => None,
+        }
+    }
+
+    /// Starts typing a filter, scrolled back to the top so the first
+    /// match shows.
+    fn start_filter(&mut self) {
+        match self.screen {
+            Screen::Overlap => self.overlap.scroll = 0,
+            Screen::Range => self.range.scroll = 0,
+            Screen::Cleanup => self.cleanup.scroll = 0,
+            Screen::Sweep => {
+                if let Some(sweep) = &mut self.sweep {
+                    sweep.scroll = 0;
+                }
+            }
+            _ => {}
+        }
+        if let Some(filter) = self.filter_mut() {
+            filter.typing = true;
+        }
+    }
+
+    /// Enter in a single-line field.
+    fn submit_field(&mut self) {
+        self.octet = None;
+        if let Some(filter) = self.filter_mut().filter(|filter| filter.typing) {
+            filter.typing = false;
+            return;
+        }
+        match self.screen {
+            Screen::Calculator => {
+                match self.input_mode {
+                    InputMode::Hosts => self.size_for_hosts(),
+                    InputMode::Subnets => self.plan_subnets(),
+                    _ => self.calculate_subnet(),
+                }
+                self.input_mode = InputMode::NoTyping;
+            }
+            Screen::Membership => {
+                self.membership.check();
+                self.membership.input_mode = MembershipInput::NoTyping;
+            }
+            Screen::Overlap => {
+                self.overlap.load_file();
+                self.overlap.input_mode = OverlapInput::NoTyping;
+            }
+            Screen::Tree => {
+                self.tree.build();
+                self.tree.input_mode = TreeInput::NoTyping;
+            }
+            Screen::Range => {
+                self.range.convert();
+                self.range.typing = false;
+            }
+            Screen::Hosts => {
+                let Some(hosts) = &mut self.hosts else {
+                    return;
+                };
+                if let Some(port) = hosts.submit_prompt(&mut self.labels) {
+                    // Checked before listing the hosts, which on a large
+                    // block would not fit in memory.
+                    if hosts.marked.is_empty() && hosts.count > sweep::MAX_SWEEP_HOSTS {
+                        hosts.message = Some(tr!(
+                            "error-probe-too-many",
+                            limit = cidr::group_digits(sweep::MAX_SWEEP_HOSTS)
+                        ));
+                        return;
+                    }
+                    let targets = hosts.scan_targets();
+                    if let Err(err) = self.start_sweep(targets, Probe::Port(port)) {
+                        if let Some(hosts) = &mut self.hosts {
+                            hosts.message = Some(err);
+                        }
+                    }
+                }
+            }
+            Screen::Quiz => {
+                if let Some(quiz) = &mut self.quiz {
+                    quiz.submit();
+                }
+            }
+            Screen::Sweep => {}
+            Screen::Ipv6 => self.ipv6.submit(),
+            Screen::Arithmetic => {
+                let block = self.block();
+                self.arithmetic.evaluate(block);
+                self.arithmetic.typing = false;
+            }
+            Screen::Cleanup | Screen::Explain => {}
+        }
+    }
+
+    /// Esc in any field. Multi-line lists are analyzed on the way out, and
+    /// a filter being typed is dropped.
+    fn finish_field(&mut self) {
+        self.octet = None;
+        if let Some(filter) = self.filter_mut().filter(|filter| filter.typing) {
+            filter.typing = false;
+            filter.input.clear();
+            return;
+        }
+        match self.screen {
+            Screen::Calculator => self.input_mode = InputMode::NoTyping,
+            Screen::Membership => self.membership.input_mode = MembershipInput::NoTyping,
+            Screen::Overlap => {
+                if matches!(self.overlap.input_mode, OverlapInput::List) {
+                    self.overlap.analyze();
+                }
+                self.overlap.input_mode = OverlapInput::NoTyping;
+            }
+            Screen::Tree => {
+                if matches!(self.tree.input_mode, TreeInput::Children) {
+                    self.tree.build();
+                }
+                self.tree.input_mode = TreeInput::NoTyping;
+            }
+            Screen::Range => self.range.typing = false,
+            Screen::Hosts => {
+                if let Some(hosts) = &mut self.hosts {
+                    hosts.prompt = None;
+                    hosts.input.clear();
+                }
+            }
+            Screen::Quiz => self.screen = Screen::Calculator,
+            Screen::Sweep | Screen::Explain => {}
+            Screen::Ipv6 => self.ipv6.submit(),
+            Screen::Arithmetic => self.arithmetic.typing = false,
+            Screen::Cleanup => {
+                self.cleanup.clean();
+                self.cleanup.typing = false;
+            }
+        }
+    }
+
+    /// Runs a screen-level action; actions meaningless on the current
+    /// screen are ignored.
+    fn perform(&mut self, action: Action) {
+        self.octet = None;
+        match (self.screen, action) {
+            (Screen::Sweep, Action::Back) if self.sweep.as_ref().is_some_and(Sweep::is_running) => {
+                if let Some(sweep) = &mut self.sweep {
+                    sweep.cancel();
+                }
+            }
+            (_, Action::Back) => self.screen = Screen::Calculator,
+            (Screen::Overlap | Screen::Range | Screen::Cleanup | Screen::Sweep, Action::Search) => {
+                self.start_filter()
+            }
+            (Screen::Calculator, Action::FocusIp) => self.input_mode = InputMode::IP,
+            (Screen::Calculator, Action::FocusMask) => self.input_mode = InputMode::Subnet,
+            (Screen::Calculator, Action::FocusHosts) => self.input_mode = InputMode::Hosts,
+            (Screen::Calculator, Action::FocusSubnets) => self.input_mode = InputMode::Subnets,
+            (Screen::Calculator, Action::Calculate) => self.calculate_subnet(),
+            (Screen::Calculator, Action::UseHostsPrefix) => self.use_hosts_prefix(),
+            (Screen::Calculator, Action::NextSubnet) => self.step_subnet(true),
+            (Screen::Calculator, Action::PrevSubnet) => self.step_subnet(false),
+            (Screen::Calculator, Action::ShrinkPrefix) => self.resize_prefix(-1),
+            (Screen::Calculator, Action::GrowPrefix) => self.resize_prefix(1),
+            (Screen::Calculator, Action::CycleCloud) => self.cycle_cloud(),
+            (Screen::Calculator, Action::ToggleCheatSheet) => {
+                self.toggle_pane(SidePane::CheatSheet);
+                self.cheat_scroll = None;
+            }
+            (Screen::Calculator, Action::ToggleTransition) => {
+                self.toggle_pane(SidePane::Transition)
+            }
+            (Screen::Calculator, Action::Up) if self.cheat_sheet_open() => {
+                self.scroll_cheat_sheet(-1)
+            }
+            (Screen::Calculator, Action::Down) if self.cheat_sheet_open() => {
+                self.scroll_cheat_sheet(1)
+            }
+            (Screen::Calculator, Action::PageUp) if self.cheat_sheet_open() => {
+                self.scroll_cheat_sheet(-8)
+            }
+            (Screen::Calculator, Action::PageDown) if self.cheat_sheet_open() => {
+                self.scroll_cheat_sheet(8)
+            }
+            (Screen::Calculator, Action::Home) if self.cheat_sheet_open() => {
+                self.cheat_scroll = Some(0)
+            }
+            (Screen::Calculator, Action::End) if self.cheat_sheet_open() => {
+                self.cheat_scroll = Some(32)
+            }
+            (Screen::Calculator, Action::ShrinkStaticRange) => self.resize_static_range(-5),
+            (Screen::Calculator, Action::GrowStaticRange) => self.resize_static_range(5),
+            (Screen::Calculator, Action::OpenMembership) => self.screen = Screen::Membership,
+            (Screen::Calculator, Action::OpenOverlap) => self.screen = Screen::Overlap,
+            (Screen::Calculator, Action::OpenTree) => self.screen = Screen::Tree,
+            (Screen::Calculator, Action::OpenRange) => self.screen = Screen::Range,
+            (Screen::Calculator, Action::OpenHosts) => self.open_hosts(),
+            (Screen::Calculator, Action::OpenQuiz) => {
+                self.quiz.get_or_insert_with(Quiz::new);
+                self.screen = Screen::Quiz;
+            }
+            (Screen::Calculator, Action::OpenIpv6) => self.screen = Screen::Ipv6,
+            (Screen::Calculator, Action::OpenArithmetic) => self.screen = Screen::Arithmetic,
+            (Screen::Calculator, Action::OpenCleanup) => self.screen = Screen::Cleanup,
+            (Screen::Calculator, Action::Explain) => self.screen = Screen::Explain,
+            (Screen::Explain, Action::Explain) => self.screen = Screen::Calculator,
+            (Screen::Explain, Action::Up) => {
+                self.explain_step = self.explain_step.saturating_sub(1)
+            }
+            (Screen::Explain, Action::Down) => {
+                self.explain_step = (self.explain_step + 1).min(explain::STEPS - 1)
+            }
+            (Screen::Explain, Action::Home) => self.explain_step = 0,
+            (Screen::Explain, Action::End) => self.explain_step = explain::STEPS - 1,
+            (Screen::Membership, Action::FocusIp) => {
+                self.membership.input_mode = MembershipInput::Ip
+            }
+            (Screen::Membership, Action::FocusCidr) => {
+                self.membership.input_mode = MembershipInput::Cidr
+            }
+            (Screen::Membership, Action::Calculate) => self.membership.check(),
+            (Screen::Overlap, Action::EditList) => self.overlap.input_mode = OverlapInput::List,
+            (Screen::Overlap, Action::LoadFile) => self.overlap.input_mode = OverlapInput::Path,
+            (Screen::Overlap, Action::Up) => {
+                self.overlap.scroll = self.overlap.scroll.saturating_sub(1)
+            }
+            (Screen::Overlap, Action::Down) => {
+                self.overlap.scroll =
+                    (self.overlap.scroll + 1).min(self.overlap.overlaps.len().saturating_sub(1))
+            }
+            (Screen::Tree, Action::FocusParent) => self.tree.input_mode = TreeInput::Parent,
+            (Screen::Tree, Action::EditList) => self.tree.input_mode = TreeInput::Children,
+            (Screen::Tree, Action::Up) => self.tree.selected = self.tree.selected.saturating_sub(1),
+            (Screen::Tree, Action::Down) => {
+                self.tree.selected =
+                    (self.tree.selected + 1).min(self.tree.rows().len().saturating_sub(1))
+            }
+            (Screen::Tree, Action::Toggle) => self.tree.toggle_selected(),
+            (Screen::Range, Action::EditRange) => self.range.typing = true,
+            (Screen::Range, Action::Up) => self.range.scroll = self.range.scroll.saturating_sub(1),
+            (Screen::Range, Action::Down) => {
+                self.range.scroll =
+                    (self.range.scroll + 1).min(self.range.blocks.len().saturating_sub(1))
+            }
+            (_, Action::GenerateUla) => self.generate_ula(),
+            (_, Action::ToggleZoom) => self.zoomed = !self.zoomed,
+            (_, Action::ClearField) => self.clear_field(),
+            (Screen::Calculator, Action::SwapIpMask) => self.swap_ip_mask(),
+            (Screen::Ipv6, Action::FocusIp) => self.ipv6.input_mode = Ipv6Input::Address,
+            (Screen::Ipv6, Action::FocusMac) => self.ipv6.input_mode = Ipv6Input::Mac,
+            (Screen::Ipv6, Action::FocusCidr) => self.ipv6.input_mode = Ipv6Input::Prefix,
+            (Screen::Arithmetic, Action::FocusIp) => self.arithmetic.typing = true,
+            (Screen::Cleanup, Action::EditList) => self.cleanup.typing = true,
+            (Screen::Cleanup, Action::Up) => {
+                self.cleanup.scroll = self.cleanup.scroll.saturating_sub(1)
+            }
+            (Screen::Cleanup, Action::Down) => {
+                self.cleanup.scroll =
+                    (self.cleanup.scroll + 1).min(self.cleanup.merged.len().saturating_sub(1))
+            }
+            (Screen::Hosts, action) => {
+                let Some(hosts) = &mut self.hosts else {
+                    return;
+                };
+                match action {
+                    Action::JumpToHost => hosts.prompt = Some(HostPrompt::Jump),
+                    Action::Search => hosts.prompt = Some(HostPrompt::Search),
+                    Action::NextMatch => hosts.search_next(&self.labels),
+                    Action::LabelHost => hosts.edit_label(&self.labels),
+                    Action::Toggle => hosts.toggle_mark(),
+                    Action::PortScan => hosts.prompt = Some(HostPrompt::Port),
+                    Action::Up => hosts.move_by(-1),
+                    Action::Down => hosts.move_by(1),
+                    Action::PageUp => hosts.page(false),
+                    Action::PageDown => hosts.page(true),
+                    Action::Home => hosts.selected = 0,
+                    Action::End => hosts.selected = hosts.count.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            (Screen::Quiz, Action::Submit) => {
+                if let Some(quiz) = &mut self.quiz {
+                    quiz.submit();
+                }
+            }
+            (Screen::Sweep, action) => {
+                let Some(sweep) = &mut self.sweep else {
+                    return;
+                };
+                match action {
+                    Action::Up => sweep.scroll = sweep.scroll.saturating_sub(1),
+                    Action::Down => {
+                        sweep.scroll = (sweep.scroll + 1).min(sweep.targets.len().saturating_sub(1))
+                    }
+                    Action::SwitchProbe => {
+                        let probe = match sweep.probe {
+                            Probe::Icmp => Probe::Tcp(80),
+                            Probe::Tcp(_) | Probe::Port(_) => Probe::Icmp,
+                        };
+                        let targets = std::mem::take(&mut sweep.targets);
+                        self.sweep = Some(Sweep::start(targets, probe));
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Recomputes every result from the parsed address and mask.
+    fn apply(&mut self, ip: Ipv4Addr, subnet: Ipv4Addr) {
+        self.ip_address = Some(ip);
+        self.subnet_mask = Some(subnet);
+        self.network_address = Some(calculate_network_address(ip, subnet));
+        self.broadcast_address = Some(calculate_broadcast_address(ip, subnet));
+        self.subnet_count = Some(calculate_subnet_count(subnet));
+        self.host_count = Some(calculate_host_count(subnet));
+        self.ip_class = Some(IpClass::of(ip));
+        self.scope = Some(classify::classify(ip));
+        self.warnings = special_use_warnings(ip, self.network_address, self.broadcast_address);
+        self.prefix_length = cidr::mask_prefix(subnet);
+        self.cheat_scroll = None;
+        if let (Some(cloud), Some(prefix)) = (self.cloud, self.prefix_length) {
+            let allowed = cloud.allowed_prefixes();
+            if !allowed.contains(&prefix) {
+                self.warnings.push(tr!(
+                    "warning-cloud-prefix",
+                    cloud = cloud,
+                    shortest = allowed.start(),
+                    longest = allowed.end()
+                ));
+            }
+        }
+        self.reverse_zones = self
+            .prefix_length
+            .map(|prefix| rdns::reverse_zones(ip, prefix))
+            .unwrap_or_default();
+        self.plan_subnets();
+        self.suggest_dhcp();
+    }
+
+    fn suggest_dhcp(&mut self) {
+        self.dhcp = self
+            .network_address
+            .zip(self.prefix_length)
+            .and_then(|(network, prefix)| dhcp::suggest(network, prefix, self.dhcp_static_percent));
+    }
+
+    /// Puts a new ULA /48 in the IPv6 screen, from wherever it was asked
+    /// for.
+    fn generate_ula(&mut self) {
+        self.screen = Screen::Ipv6;
+        match ula::generate() {
+            Ok(ula) => {
+                self.ipv6.input.set(format!("{}/48", ula.prefix));
+                self.ipv6.convert();
+                self.ipv6.note = Some(tr!("ipv6-ula-source", source = ula.source));
+            }
+            Err(err) => self.ipv6.note = Some(err),
+        }
+    }
+
+    /// Shows `pane` beside the results, or hides it if it is already shown.
+    fn toggle_pane(&mut self, pane: SidePane) {
+        self.side_pane = (self.side_pane != Some(pane)).then_some(pane);
+    }
+
+    fn cheat_sheet_open(&self) -> bool {
+        self.side_pane == Some(SidePane::CheatSheet)
+    }
+
+    /// The first cheat sheet row to show, a couple above the current prefix
+    /// unless scrolled by hand.
+    pub fn cheat_sheet_offset(&self) -> usize {
+        self.cheat_scroll
+            .unwrap_or_else(|| self.prefix_length.unwrap_or(0).saturating_sub(2) as usize)
+    }
+
+    fn scroll_cheat_sheet(&mut self, delta: i32) {
+        let offset = (self.cheat_sheet_offset() as i32 + delta).clamp(0, 32);
+        self.cheat_scroll = Some(offset as usize);
+    }
+
+    /// Switches to the next cloud provider's reserved-address rules.
+    fn cycle_cloud(&mut self) {
+        self.cloud = Cloud::next(self.cloud);
+        if let (Some(ip), Some(subnet)) = (self.ip_address, self.subnet_mask) {
+            self.apply(ip, subnet);
+        }
+    }
+
+    /// Moves the split between static addresses and the DHCP pool.
+    fn resize_static_range(&mut self, delta: i32) {
+        self.dhcp_static_percent = (self.dhcp_static_percent as i32 + delta).clamp(0, 90) as u32;
+        self.suggest_dhcp();
+    }
+
+    /// Grows or shrinks the prefix of the current calculation, keeping the
+    /// subnet field in sync.
+    fn resize_prefix(&mut self, delta: i32) {
+        let (Some(ip), Some(subnet)) = (self.ip_address, self.subnet_mask) else {
+            return;
+        };
+        let prefix = self
+            .prefix_length
+            .unwrap_or_else(|| u32::from(subnet).leading_ones());
+        let prefix = (prefix as i32 + delta).clamp(8, 32) as u32;
+        let subnet = Ipv4Addr::from(cidr::prefix_mask(prefix));
+        self.subnet_input.set(subnet.to_string());
+        self.apply(ip, subnet);
+    }
+
+    fn plan_subnets(&mut self) {
+        let required = self.subnets_input.trim().parse().ok();
+        self.subnetting = self
+            .prefix_length
+            .zip(required)
+            .and_then(|(prefix, required)| cidr::subnets_for_count(prefix, required));
+    }
+
+    fn size_for_hosts(&mut self) {
+        self.hosts_prefix = self
+            .hosts_input
+            .trim()
+            .parse()
+            .ok()
+            .and_then(cidr::prefix_for_hosts);
+    }
+
+    /// Fills the calculator with an address and mask and calculates.
+    fn prefill(&mut self, ip: Ipv4Addr, netmask: Ipv4Addr) {
+        self.ip_input.set(ip.to_string());
+        self.subnet_input.set(netmask.to_string());
+        self.input_mode = InputMode::NoTyping;
+        self.screen = Screen::Calculator;
+        self.calculate_subnet();
+    }
+
+    /// Copies the mask found by the hosts-needed calculator into the subnet
+    /// field and recalculates.
+    fn use_hosts_prefix(&mut self) {
+        if let Some(prefix) = self.hosts_prefix {
+            self.subnet_input
+                .set(Ipv4Addr::from(cidr::prefix_mask(prefix)).to_string());
+            self.calculate_subnet();
+        }
+    }
+
+    /// Looks up who holds the calculated address, showing the answer
+    /// beside the results. Asking again for the same address hides it.
+    fn lookup_rdap(&mut self) -> Result<(), String> {
+        let Some(ip) = self.ip_address else {
+            return Err(tr!("error-rdap-no-address"));
+        };
+        if !matches!(self.scope, Some((Scope::Public, _))) {
+            return Err(tr!("error-rdap-not-public", ip = ip));
+        }
+        let shown = self.side_pane == Some(SidePane::Rdap);
+        if shown && self.rdap.as_ref().is_some_and(|lookup| lookup.ip == ip) {
+            self.side_pane = None;
+            return Ok(());
+        }
+        self.rdap = Some(RdapLookup::start(ip));
+        self.side_pane = Some(SidePane::Rdap);
+        Ok(())
+    }
+
+    /// Turns reverse DNS names for the host list and sweep on or off.
+    fn toggle_names(&mut self, nameserver: Option<IpAddr>) -> Result<(), String> {
+        self.names = match self.names {
+            Some(_) => None,
+            None => Some(Resolver::start(nameserver)?),
+        };
+        Ok(())
+    }
+
+    /// Queues names for the hosts on the current page and, once per
+    /// sweep, its targets, then collects the answers so far.
+    fn resolve_listed(&mut self) {
+        let Some(names) = &mut self.names else {
+            return;
+        };
+        if let Some(hosts) = &self.hosts {
+            let page = hosts.page_size;
+            let top = hosts.selected / page * page;
+            names.request((top..(top + page).min(hosts.count)).map(|i| hosts.host(i)));
+        }
+        if let Some(sweep) = &self.sweep {
+            if names.sweep_queued != Some(sweep.started) {
+                names.request(sweep.targets.iter().copied());
+                names.sweep_queued = Some(sweep.started);
+            }
+        }
+        names.poll();
+    }
+
+    /// Pins the calculated block so later calculations show beside it.
+    /// Pinning again while the comparison is shown unpins it.
+    fn pin_compare(&mut self) -> Result<(), String> {
+        if self.side_pane == Some(SidePane::Compare) {
+            self.side_pane = None;
+            self.pinned = None;
+            return Ok(());
+        }
+        let Some(block) = self.block() else {
+            return Err(tr!("error-compare-no-subnet"));
+        };
+        self.pinned = Some(block);
+        self.side_pane = Some(SidePane::Compare);
+        Ok(())
+    }
+
+    /// Starts probing every usable host of the calculated subnet.
+    fn open_sweep(&mut self, probe: Probe) -> Result<(), String> {
+        let (Some(network), Some(prefix)) = (self.network_address, self.prefix_length) else {
+            return Err(tr!("error-sweep-no-subnet"));
+        };
+        let hosts = HostList::for_subnet(network, prefix);
+        if hosts.count > sweep::MAX_SWEEP_HOSTS {
+            return Err(tr!(
+                "error-sweep-too-large",
+                block = format!("{}/{}", network, prefix),
+                limit = cidr::group_digits(sweep::MAX_SWEEP_HOSTS)
+            ));
+        }
+        self.start_sweep(hosts.scan_targets(), probe)
+    }
+
+    fn start_sweep(&mut self, targets: Vec<Ipv4Addr>, probe: Probe) -> Result<(), String> {
+        if targets.len() as u64 > sweep::MAX_SWEEP_HOSTS {
+            return Err(tr!(
+                "error-probe-too-many",
+                limit = cidr::group_digits(sweep::MAX_SWEEP_HOSTS)
+            ));
+        }
+        self.sweep = Some(Sweep::start(targets, probe));
+        self.screen = Screen::Sweep;
+        Ok(())
+    }
+
+    fn open_hosts(&mut self) {
+        if let (Some(network), Some(prefix)) = (self.network_address, self.prefix_length) {
+            self.hosts = Some(HostList::for_subnet(network, prefix));
+            self.screen = Screen::Hosts;
+        }
+    }
+
+    /// Empties the field being typed in as one undoable step.
+    fn clear_field(&mut self) {
+        if let Some((input, _)) = self.active_input() {
+            input.clear();
+        }
+        self.suggestion = 0;
+        self.octet = None;
+    }
+
+    /// Exchanges the IP and mask fields and recalculates, for when they
+    /// were typed the wrong way round.
+    fn swap_ip_mask(&mut self) {
+        let ip = self.ip_input.to_string();
+        let mask = self.subnet_input.to_string();
+        self.ip_input.set(mask);
+        self.subnet_input.set(ip);
+        self.octet = None;
+        self.calculate_subnet();
+    }
+
+    /// Empties every input and result in the workspace. The history and
+    /// the DHCP and cloud settings stay, as does the screen unless it has
+    /// nothing to show without a result.
+    fn reset(&mut self) {
+        let screen = match self.screen {
+            Screen::Hosts | Screen::Quiz | Screen::Sweep => Screen::Calculator,
+            screen => screen,
+        };
+        *self = Self {
+            screen,
+            history: std::mem::take(&mut self.history),
+            dhcp_static_percent: self.dhcp_static_percent,
+            cloud: self.cloud,
+            side_pane: self.side_pane,
+            zoomed: self.zoomed,
+            ..Self::new()
+        };
+    }
+
+    /// Moves to the adjacent subnet of the same size and recalculates.
+    fn step_subnet(&mut self, forward: bool) {
+        let (Ok(ip), Ok(subnet)) = (
+            self.ip_input.parse::<Ipv4Addr>(),
+            self.subnet_input.parse::<Ipv4Addr>(),
+        ) else {
+            return;
+        };
+        let Some(prefix) = cidr::mask_prefix(subnet) else {
+            return;
+        };
+        let size = cidr::block_size(prefix);
+        let network = u64::from(u32::from(cidr::network_of(ip, prefix)));
+        let target = if forward {
+            network.checked_add(size)
+        } else {
+            network.checked_sub(size)
+        };
+        if let Some(target) = target.and_then(|t| u32::try_from(t).ok()) {
+            self.ip_input.set(Ipv4Addr::from(target).to_string());
+            self.calculate_subnet();
+        }
+    }
+}
+
+/// Applies one event to the app. Drawing happens separately, after
+/// every update.
+pub fn update(app: &mut App, event: Event) {
+    match event {
+        Event::Key(key) => handle_key(app, key),
+        Event::Paste(text) => app.paste(&text),
+        Event::Resize(width, height) => app.size = Rect::new(0, 0, width, height),
+        Event::Tick => app.tick(),
+    }
+    app.fit_pages();
+}
+
+/// Routes a key to the open prompt, the keymap or the focused field.
+///
+/// Outside a field every key goes through the keymap. Inside one, only
+/// Ctrl/Alt chords do, plus letters bound to field switches while the field
+/// takes addresses; everything else edits the text.
+fn handle_key(app: &mut App, key: KeyEvent) {
+    if app.session_prompt.is_some() {
+        handle_session_key(app, key.code);
+        return;
+    }
+    if app.palette.is_some() {
+        handle_palette_key(app, key.code);
+        return;
+    }
+    if app.interfaces.is_some() {
+        handle_interface_key(app, key.code);
+        return;
+    }
+    if app.export.is_some() {
+        handle_export_key(app, key.code);
+        return;
+    }
+    if app.command_line.is_some() {
+        handle_command_key(app, key.code);
+        return;
+    }
+    let ws = &mut app.workspaces[app.active];
+    let context = ws.context();
+    let field = ws.active_input().map(|(_, kind)| kind);
+    let action = app.keymap.lookup(context, key);
+    tracing::trace!(code = ?key.code, modifiers = ?key.modifiers, ?action, "key");
+    let chord = key
+        .modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+    let letter = matches!(key.code, KeyCode::Char(c) if c.is_alphabetic());
+    match (field, action) {
+        (None, Some(action)) => perform(app, action),
+        (Some(_), Some(action)) if chord => perform(app, action),
+        (Some(FieldKind::Address | FieldKind::Quad | FieldKind::Mask), Some(action))
+            if letter && action.applies_while_typing() =>
+        {
+            perform(app, action)
+        }
+        (Some(_), _) if chord => {}
+        (Some(kind), _) => edit_field(&mut app.workspaces[app.active], key.code, kind),
+        (None, None) => {}
+    }
+    // Zooming hides the input fields, so focusing one restores the layout.
+    let ws = &mut app.workspaces[app.active];
+    if ws.zoomed && ws.active_input().is_some() {
+        ws.zoomed = false;
+    }
+}
+
+fn perform(app: &mut App, action: Action) {
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::CommandLine => app.command_line = Some(Input::default()),
+        Action::CommandPalette => app.palette = Some(Palette::new()),
+        Action::PickInterface => app.interfaces = Some(InterfacePicker::new()),
+        Action::Export => {
+            let ws = app.workspace();
+            match ws.network_address.zip(ws.prefix_length) {
+                Some((network, prefix)) => {
+                    let info = SubnetInfo::new(
+                        network,
+                        prefix,
+                        ws.dhcp.as_ref(),
+                        ws.subnetting.as_ref(),
+                        &ws.labels,
+                    );
+                    app.export = Some(ExportPicker::new(info));
+                }
+                None => app.toasts.error(tr!("error-export-no-subnet")),
+            }
+        }
+        Action::ExportReport => match report::save(app.workspace(), &app.geoip) {
+            Ok(path) => app
+                .toasts
+                .info(tr!("toast-report-written", path = path.display())),
+            Err(err) => app.toasts.error(err),
+        },
+        Action::OpenSweep => {
+            if let Err(err) = app.workspaces[app.active].open_sweep(Probe::Icmp) {
+                app.toasts.error(err);
+            }
+        }
+        Action::LookupRdap => {
+            if let Err(err) = app.workspaces[app.active].lookup_rdap() {
+                app.toasts.error(err);
+            }
+        }
+        Action::ResolveNames => {
+            if let Err(err) = app.workspaces[app.active].toggle_names(app.nameserver) {
+                app.toasts.error(err);
+            }
+        }
+        Action::PinCompare => {
+            if let Err(err) = app.workspaces[app.active].pin_compare() {
+                app.toasts.error(err);
+            }
+        }
+        Action::NewWorkspace => app.open_workspace(),
+        Action::CloseWorkspace => app.close_workspace(),
+        Action::NextWorkspace => app.cycle_workspace(true),
+        Action::PrevWorkspace => app.cycle_workspace(false),
+        Action::SelectWorkspace(n) => {
+            if n <= app.workspaces.len() {
+                app.active = n - 1;
+            }
+        }
+        Action::SaveSession => app.session_prompt = Some(SessionPrompt::new(PromptKind::Save)),
+        Action::LoadSession => app.session_prompt = Some(SessionPrompt::new(PromptKind::Load)),
+        Action::ToggleDebugLog => app.debug_log = !app.debug_log,
+        Action::ResetWorkspace => {
+            app.workspaces[app.active].reset();
+            app.toasts.info(tr!("toast-workspace-reset"));
+        }
+        Action::Undo => {
+            if let Some((input, _)) = app.workspaces[app.active].active_input() {
+                input.undo();
+            }
+        }
+        Action::Redo => {
+            if let Some((input, _)) = app.workspaces[app.active].active_input() {
+                input.redo();
+            }
+        }
+        action => app.workspaces[app.active].perform(action),
+    }
+}
+
+fn edit_field(ws: &mut Workspace, code: KeyCode, kind: FieldKind) {
+    match code {
+        KeyCode::Tab => return ws.accept_suggestion(),
+        KeyCode::Up => return ws.move_suggestion(-1),
+        KeyCode::Down => return ws.move_suggestion(1),
+        _ => {}
+    }
+    ws.suggestion = 0;
+    if matches!(kind, FieldKind::Quad | FieldKind::Mask) && edit_quad(ws, code, kind) {
+        return;
+    }
+    let Some((input, _)) = ws.active_input() else {
+        return;
+    };
+    match code {
+        KeyCode::Char(c) => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Enter if kind == FieldKind::Multiline => input.push('\n'),
+        KeyCode::Enter => ws.submit_field(),
+        KeyCode::Esc => ws.finish_field(),
+        _ => {}
+    }
+}
+
+fn handle_interface_key(app: &mut App, code: KeyCode) {
+    let Some(picker) = &mut app.interfaces else {
+        return;
+    };
+    match code {
+        KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+        KeyCode::Down => {
+            picker.selected = (picker.selected + 1).min(picker.addresses.len().saturating_sub(1))
+        }
+        KeyCode::Enter => {
+            if let Some(address) = picker.chosen() {
+                let (ip, netmask) = (address.ip, address.netmask);
+                app.workspaces[app.active].prefill(ip, netmask);
+                app.interfaces = None;
+            }
+        }
+        KeyCode::Esc => app.interfaces = None,
+        _ => {}
+    }
+}
+
+fn handle_export_key(app: &mut App, code: KeyCode) {
+    let Some(picker) = &mut app.export else {
+        return;
+    };
+    match code {
+        KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+        KeyCode::Down => picker.selected = (picker.selected + 1).min(export::FORMATS.len() - 1),
+        KeyCode::Enter => {
+            match picker.save() {
+                Ok(path) => app
+                    .toasts
+                    .info(tr!("toast-exported", path = path.display())),
+                Err(err) => app.toasts.error(tr!("toast-export-failed", error = err)),
+            }
+            app.export = None;
+        }
+        KeyCode::Esc => app.export = None,
+        _ => {}
+    }
+}
+
+fn handle_palette_key(app: &mut App, code: KeyCode) {
+    let Some(palette) = &mut app.palette else {
+        return;
+    };
+    match code {
+        KeyCode::Char(c) => {
+            palette.query.push(c);
+            palette.selected = 0;
+        }
+        KeyCode::Backspace => {
+            palette.query.pop();
+            palette.selected = 0;
+        }
+        KeyCode::Up => palette.selected = palette.selected.saturating_sub(1),
+        KeyCode::Down => {
+            palette.selected = (palette.selected + 1).min(palette.matches().len().saturating_sub(1))
+        }
+        KeyCode::Enter => {
+            if let Some(action) = app.palette.take().and_then(|p| p.chosen()) {
+                perform(app, action);
+            }
+        }
+        KeyCode::Esc => app.palette = None,
+        _ => {}
+    }
+}
+
+/// Octet-aware editing of a dotted quad: digits are checked as they are
+/// typed, dots close full octets, and Left/Right select an octet to
+/// overwrite. Returns whether the key was handled.
+fn edit_quad(ws: &mut Workspace, code: KeyCode, kind: FieldKind) -> bool {
+    let mut cursor = ws.octet;
+    let Some((input, _)) = ws.active_input() else {
+        return false;
+    };
+    let text = input.to_string();
+    let append = match kind {
+        FieldKind::Mask => octet::append_mask,
+        _ => octet::append,
+    };
+    match code {
+        // A prefix length has no octets to pick.
+        KeyCode::Left | KeyCode::Right if text.starts_with('/') => return false,
+        KeyCode::Left | KeyCode::Right => {
+            cursor = OctetCursor::step(cursor, &text, code == KeyCode::Right)
+        }
+        KeyCode::Char(c) if cursor.is_some() => {
+            match OctetCursor::type_char(&mut cursor, &text, c) {
+                Some(edited) if edited != text => input.set(edited),
+                _ => {}
+            }
+        }
+        KeyCode::Char(c) => match append(&text, c) {
+            Append::Accept => input.push(c),
+            Append::AcceptWithDot => {
+                input.push(c);
+                input.push('.');
+            }
+            Append::Reject => {}
+        },
+        KeyCode::Backspace => match &mut cursor {
+            Some(selected) => input.set(selected.backspace(&text)),
+            None => return false,
+        },
+        _ => return false,
+    }
+    ws.octet = cursor;
+    true
+}
+
+/// The `:` command line runs any action by its config name.
+fn handle_command_key(app: &mut App, code: KeyCode) {
+    let Some(line) = &mut app.command_line else {
+        return;
+    };
+    match code {
+        KeyCode::Char(c) => line.push(c),
+        KeyCode::Backspace => {
+            line.pop();
+        }
+        KeyCode::Enter => {
+            let command = app
+                .command_line
+                .take()
+                .map(|line| line.trim().to_string())
+                .unwrap_or_default();
+            let action = match command.as_str() {
+                "" => return,
+                "q" => Some(Action::Quit),
+                name => Action::from_name(name),
+            };
+            match action {
+                Some(action) => perform(app, action),
+                None => app
+                    .toasts
+                    .error(tr!("toast-unknown-command", command = command)),
+            }
+        }
+        KeyCode::Esc => app.command_line = None,
+        _ => {}
+    }
+}
+
+fn handle_session_key(app: &mut App, code: KeyCode) {
+    let Some(prompt) = &mut app.session_prompt else {
+        return;
+    };
+    match code {
+        KeyCode::Char(c) => {
+            prompt.name.push(c);
+            prompt.selected = 0;
+        }
+        KeyCode::Backspace => {
+            prompt.name.pop();
+            prompt.selected = 0;
+        }
+        KeyCode::Up => prompt.selected = prompt.selected.saturating_sub(1),
+        KeyCode::Down => {
+            prompt.selected = (prompt.selected + 1).min(prompt.matches().len().saturating_sub(1))
+        }
+        KeyCode::Tab => {
+            if let Some(name) = prompt.matches().get(prompt.selected) {
+                prompt.name = name.to_string();
+            }
+        }
+        KeyCode::Enter => {
+            let Some(name) = prompt.chosen() else {
+                return;
+            };
+            let kind = app.session_prompt.take().map(|p| p.kind);
+            match kind {
+                Some(PromptKind::Save) => app.save_session(&name),
+                Some(PromptKind::Load) => app.load_session(&name),
+                None => {}
+            }
+        }
+        KeyCode::Esc => app.session_prompt = None,
+        _ => {}
+    }
+}
+
+fn special_use_warnings(
+    ip: Ipv4Addr,
+    network: Option<Ipv4Addr>,
+    broadcast: Option<Ipv4Addr>,
+) -> Vec<String> {
+    let (Some(first), Some(last)) = (network, broadcast) else {
+        return Vec::new();
+    };
+    classify::hazards(first, last)
+        .into_iter()
+        .map(|r| {
+            let scope = r.scope.to_string().to_lowercase();
+            let block = format!("{}/{}", r.network, r.prefix);
+            if r.contains(ip) {
+                tr!(
+                    "warning-inside",
+                    ip = ip,
+                    scope = scope,
+                    block = block,
+                    rfc = r.rfc
+                )
+            } else {
+                tr!(
+                    "warning-overlaps",
+                    first = first,
+                    last = last,
+                    scope = scope,
+                    block = block,
+                    rfc = r.rfc
+                )
+            }
+        })
+        .collect()
+}
+
+fn calculate_network_address(ip: Ipv4Addr, subnet_mask: Ipv4Addr) -> Ipv4Addr {
+    let ip_octets = ip.octets();
+    let subnet_mask_octets = subnet_mask.octets();
+    Ipv4Addr::new(
+        ip_octets[0] & subnet_mask_octets[0],
+        ip_octets[1] & subnet_mask_octets[1],
+        ip_octets[2] & subnet_mask_octets[2],
+        ip_octets[3] & subnet_mask_octets[3],
+    )
+}
+
+fn calculate_broadcast_address(ip: Ipv4Addr, subnet_mask: Ipv4Addr) -> Ipv4Addr {
+    let ip_octets = ip.octets();
+    let subnet_mask_octets = subnet_mask.octets();
+    Ipv4Addr::new(
+        ip_octets[0] | !subnet_mask_octets[0],
+        ip_octets[1] | !subnet_mask_octets[1],
+        ip_octets[2] | !subnet_mask_octets[2],
+        ip_octets[3] | !subnet_mask_octets[3],
+    )
+}
+
+/// Addresses in the block; a /0 holds 2^32, one more than a `u32` can.
+fn calculate_subnet_count(subnet_mask: Ipv4Addr) -> u64 {
+    cidr::block_size(u32::from(subnet_mask).count_ones())
+}
+
+/// Usable hosts, counting both addresses of a /31 and the single address
+/// of a /32 as RFC 3021 does.
+fn calculate_host_count(subnet_mask: Ipv4Addr) -> u64 {
+    cidr::usable_hosts(u32::from(subnet_mask).count_ones())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key::{KeyCode, KeyEvent, KeyModifiers};
+
+    /// An app with the default keys and English text, whatever the
+    /// config file and locale of the machine running the tests.
+    fn app() -> App {
+        let config = Config {
+            language: Some("en".to_string()),
+            ..Config::default()
+        };
+        App::with_config(config, false, Vec::new())
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        update(app, Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+    }
+
+    fn ctrl(app: &mut App, c: char) {
+        update(
+            app,
+            Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)),
+        );
+    }
+
+    fn type_keys(app: &mut App, keys: &str) {
+        for c in keys.chars() {
+            press(app, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn typing_both_fields_and_enter_calculates() {
+        let mut app = app();
+        type_keys(&mut app, "i192.168.1.10s255.255.255.0");
+        press(&mut app, KeyCode::Enter);
+        let ws = app.workspace();
+        assert_eq!(ws.network_address, Some(Ipv4Addr::new(192, 168, 1, 0)));
+        assert_eq!(ws.broadcast_address, Some(Ipv4Addr::new(192, 168, 1, 255)));
+        assert_eq!(ws.prefix_length, Some(24));
+        assert_eq!(
+            ws.history,
+            [(Ipv4Addr::new(192, 168, 1, 10), ws.subnet_mask.unwrap())]
+        );
+    }
+
+    #[test]
+    fn full_octets_close_themselves_and_bad_digits_are_dropped() {
+        let mut app = app();
+        type_keys(&mut app, "i19216826");
+        assert_eq!(app.workspace().ip_input.as_str(), "192.168.26");
+        type_keys(&mut app, "7.");
+        assert_eq!(app.workspace().ip_input.as_str(), "192.168.26.");
+    }
+
+    #[test]
+    fn prefix_length_in_the_subnet_field_becomes_a_mask() {
+        let mut app = app();
+        type_keys(&mut app, "i10.1.2.3s/22");
+        press(&mut app, KeyCode::Enter);
+        let ws = app.workspace();
+        assert_eq!(ws.subnet_input.as_str(), "255.255.252.0");
+        assert_eq!(ws.network_address, Some(Ipv4Addr::new(10, 1, 0, 0)));
+    }
+
+    #[test]
+    fn tab_accepts_a_mask_completion() {
+        let mut app = app();
+        type_keys(&mut app, "s255.255.255.1");
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.workspace().subnet_input.as_str(), "255.255.255.128");
+    }
+
+    #[test]
+    fn left_selects_an_octet_to_overwrite() {
+        let mut app = app();
+        type_keys(&mut app, "i10.0.0.1");
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Left);
+        type_keys(&mut app, "7");
+        assert_eq!(app.workspace().ip_input.as_str(), "10.0.7.1");
+    }
+
+    #[test]
+    fn workspaces_open_and_close_from_inside_a_field() {
+        let mut app = app();
+        type_keys(&mut app, "i10.0.0.1");
+        ctrl(&mut app, 't');
+        assert_eq!((app.workspaces.len(), app.active), (2, 1));
+        assert!(app.workspace().ip_input.is_empty());
+        ctrl(&mut app, 'w');
+        assert_eq!((app.workspaces.len(), app.active), (1, 0));
+        assert_eq!(app.workspace().ip_input.as_str(), "10.0.0.1");
+    }
+}
@@ -0,0 +1,270 @@
+use std::net::Ipv4Addr;
+
+use crate::{
+    cidr::{self, Cidr},
+    i18n::tr,
+    input::Input,
+    range,
+};
+
+fn usage() -> String {
+    tr!("arith-usage")
+}
+
+/// Address arithmetic: offsets into the calculated block, free-form
+/// `IP + N` and `IP - N`, or the distance `IP - IP`.
+pub struct Arithmetic {
+    pub input: Input,
+    pub typing: bool,
+    pub result: Option<Result<Vec<String>, String>>,
+}
+
+impl Arithmetic {
+    pub fn new() -> Self {
+        Self {
+            input: Input::default(),
+            typing: false,
+            result: None,
+        }
+    }
+
+    pub fn evaluate(&mut self, block: Option<Cidr>) {
+        self.result = Some(evaluate(&self.input, block));
+    }
+}
+
+/// `+N` counts up from the network address of `block`, `-N` back from
+/// its broadcast address; either is refused if it leaves the block.
+pub fn evaluate(input: &str, block: Option<Cidr>) -> Result<Vec<String>, String> {
+    let input = input.trim();
+    let block = || block.ok_or_else(|| tr!("arith-no-subnet"));
+    if let Some(n) = input.strip_prefix('+') {
+        return within(block()?, true, count(n)?);
+    }
+    if let Some(n) = input.strip_prefix('-') {
+        return within(block()?, false, count(n)?);
+    }
+    let Some(at) = input.find(['+', '-']) else {
+        return Err(usage());
+    };
+    let ip: Ipv4Addr = input[..at].trim().parse().map_err(|_| usage())?;
+    let sign = &input[at..at + 1];
+    if let (Ok(other), "-") = (input[at + 1..].trim().parse::<Ipv4Addr>(), sign) {
+        return Ok(distance(ip, other));
+    }
+    let n = count(&input[at + 1..])?;
+    let delta = if sign == "+" { n } else { -n };
+    let address = cidr::offset(ip, delta).ok_or_else(|| {
+        tr!(
+            "arith-out-of-space",
+            expression = format!("{} {} {}", ip, sign, n)
+        )
+    })?;
+    let mut lines = vec![format!("{} {} {} = {}", ip, sign, n, address)];
+    if let Ok(block) = block() {
+        let inside = block.contains(&Cidr::new(address, 32));
+        lines.push(if inside {
+            tr!("arith-inside", address = address, block = block)
+        } else {
+            tr!("arith-outside", address = address, block = block)
+        });
+    }
+    Ok(lines)
+}
+
+fn count(input: &str) -> Result<i64, String> {
+    input
+        .trim()
+        .parse::<u32>()
+        .map(i64::from)
+        .map_err(|_| usage())
+}
+
+fn within(block: Cidr, forward: bool, n: i64) -> Result<Vec<String>, String> {
+    let last = block.size() as i64 - 1;
+    if n > last {
+        return Err(if forward {
+            tr!(
+                "arith-past-broadcast",
+                n = n,
+                broadcast = Ipv4Addr::from(block.last()),
+                block = block,
+                last = last
+            )
+        } else {
+            tr!(
+                "arith-before-network",
+                n = n,
+                network = block.network,
+                block = block,
+                last = last
+            )
+        });
+    }
+    let from = if forward { block.first() } else { block.last() };
+    let delta = if forward { n } else { -n };
+    let address = cidr::offset(Ipv4Addr::from(from), delta).ok_or_else(usage)?;
+    let index = u64::from(u32::from(address) - block.first());
+    let role = match index {
+        0 if block.prefix < 31 => tr!("arith-role-network"),
+        i if i == last as u64 && block.prefix < 31 => tr!("arith-role-broadcast"),
+        i if block.prefix >= 31 => tr!("arith-role-host", number = i + 1, count = block.size()),
+        i => tr!(
+            "arith-role-host",
+            number = i,
+            count = cidr::group_digits(cidr::usable_hosts(block.prefix))
+        ),
+    };
+    Ok(vec![
+        tr!("arith-address", address = address),
+        if forward {
+            tr!("arith-from-network", n = n, role = role)
+        } else {
+            tr!("arith-from-broadcast", n = n, role = role)
+        },
+    ])
+}
+
+/// How far apart two addresses are, and the blocks that span them.
+pub fn distance(a: Ipv4Addr, b: Ipv4Addr) -> Vec<String> {
+    let (start, end) = (a.min(b), a.max(b));
+    let gap = u64::from(u32::from(end) - u32::from(start));
+    let mut lines = vec![
+        tr!(
+            "arith-distance",
+            gap = cidr::group_digits(gap),
+            start = start,
+            end = end
+        ),
+        tr!(
+            "arith-between",
+            between = cidr::group_digits(gap.saturating_sub(1)),
+            inclusive = cidr::group_digits(gap + 1)
+        ),
+    ];
+    let blocks = range::range_to_cidrs(start, end);
+    if let [block] = blocks.as_slice() {
+        lines.push(tr!("arith-spanned", block = block));
+    } else {
+        let common = (u32::from(start) ^ u32::from(end)).leading_zeros();
+        let covering = Cidr::new(start, common);
+        lines.push(tr!("arith-not-spanned", count = blocks.len()));
+        lines.push(tr!(
+            "arith-covering",
+            block = covering,
+            size = cidr::group_digits(covering.size())
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The expected text below is English whatever the locale.
+    fn english() {
+        let _ = crate::i18n::init(Some("en"));
+    }
+
+    fn block() -> Option<Cidr> {
+        Cidr::parse("192.168.1.0/24")
+    }
+
+    #[test]
+    fn offsets_count_from_either_end_of_the_block() {
+        english();
+        assert_eq!(
+            evaluate("+1", block()).unwrap(),
+            [
+                "Address: 192.168.1.1",
+                "+1 from the network is host 1 of 254"
+            ]
+        );
+        assert_eq!(
+            evaluate(" -0 ", block()).unwrap(),
+            [
+                "Address: 192.168.1.255",
+                "-0 from the broadcast is the broadcast address"
+            ]
+        );
+        assert_eq!(
+            evaluate("+0", block()).unwrap()[1],
+            "+0 from the network is the network address"
+        );
+    }
+
+    #[test]
+    fn offsets_stay_inside_the_block() {
+        english();
+        assert_eq!(
+            evaluate("+256", block()),
+            Err(
+                "+256 is past the broadcast address 192.168.1.255; 192.168.1.0/24 ends at +255"
+                    .to_string()
+            )
+        );
+        assert!(evaluate("-256", block()).is_err());
+        assert_eq!(
+            evaluate("+1", None),
+            Err("Calculate a subnet first to offset into it".to_string())
+        );
+    }
+
+    #[test]
+    fn point_to_point_blocks_count_every_address() {
+        english();
+        assert_eq!(
+            evaluate("+0", Cidr::parse("10.0.0.0/31")).unwrap()[1],
+            "+0 from the network is host 1 of 2"
+        );
+    }
+
+    #[test]
+    fn free_form_offsets() {
+        english();
+        assert_eq!(
+            evaluate("10.0.0.250 + 10", block()).unwrap(),
+            [
+                "10.0.0.250 + 10 = 10.0.1.4",
+                "10.0.1.4 is outside 192.168.1.0/24"
+            ]
+        );
+        assert_eq!(
+            evaluate("192.168.1.10-5", None).unwrap(),
+            ["192.168.1.10 - 5 = 192.168.1.5"]
+        );
+        assert_eq!(
+            evaluate("255.255.255.255 + 1", None),
+            Err("255.255.255.255 + 1 leaves the IPv4 address space".to_string())
+        );
+    }
+
+    #[test]
+    fn distance_between_addresses() {
+        english();
+        assert_eq!(
+            evaluate("10.0.0.7 - 10.0.0.0", None).unwrap(),
+            [
+                "Distance: 7 addresses from 10.0.0.0 to 10.0.0.7",
+                "6 addresses between them, 8 counting both ends",
+                "Spanned exactly by 10.0.0.0/29",
+            ]
+        );
+        assert_eq!(
+            evaluate("10.0.0.1 - 10.0.0.6", None).unwrap()[2..],
+            [
+                "No single prefix spans exactly; it takes 4 blocks",
+                "Smallest block containing both: 10.0.0.0/29 (8 addresses)",
+            ]
+        );
+    }
+
+    #[test]
+    fn unreadable_input_shows_usage() {
+        english();
+        for input in ["", "abc", "+x", "10.0.0.1 * 2", "10.0.0.1 + -3"] {
+            assert_eq!(evaluate(input, block()), Err(usage()), "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::{fmt, net::Ipv4Addr};
+
+/// Netmask for a prefix length as a host-order integer (`/0` yields 0).
+pub fn prefix_mask(prefix: u32) -> u32 {
+    u32::MAX.checked_shl(32 - prefix).unwrap_or(0)
+}
+
+/// Prefix length of a dotted mask, or `None` if the mask is not contiguous.
+pub fn mask_prefix(mask: Ipv4Addr) -> Option<u32> {
+    let bits = u32::from(mask);
+    let prefix = bits.leading_ones();
+    (bits == prefix_mask(prefix)).then_some(prefix)
+}
+
+/// Usable host addresses in a block; /31 and /32 follow RFC 3021.
+pub fn usable_hosts(prefix: u32) -> u64 {
+    match prefix {
+        32 => 1,
+        31 => 2,
+        _ => block_size(prefix) - 2,
+    }
+}
+
+/// Smallest block (longest prefix) with at least `hosts` usable addresses.
+pub fn prefix_for_hosts(hosts: u64) -> Option<u32> {
+    (0..=32).rev().find(|&prefix| usable_hosts(prefix) >= hosts)
+}
+
+/// Result of dividing a block into a number of equal subnets.
+pub struct Subnetting {
+    /// How many subnets were asked for, at most `subnets`.
+    pub required: u64,
+    pub borrowed_bits: u32,
+    pub new_prefix: u32,
+    pub subnets: u64,
+    pub hosts_per_subnet: u64,
+}
+
+/// Borrows enough host bits from a /`prefix` block for `required` subnets.
+pub fn subnets_for_count(prefix: u32, required: u64) -> Option<Subnetting> {
+    if required == 0 {
+        return None;
+    }
+    let borrowed_bits = 64 - (required - 1).leading_zeros();
+    let new_prefix = prefix + borrowed_bits;
+    (new_prefix <= 32).then(|| Subnetting {
+        required,
+        borrowed_bits,
+        new_prefix,
+        subnets: 1 << borrowed_bits,
+        hosts_per_subnet: usable_hosts(new_prefix),
+    })
+}
+
+/// Parses `a.b.c.d/nn` or `a.b.c.d/m.m.m.m` into an address and prefix length.
+pub fn parse_cidr(input: &str) -> Option<(Ipv4Addr, u32)> {
+    let (addr, prefix) = input.trim().split_once('/')?;
+    let addr = addr.trim().parse::<Ipv4Addr>().ok()?;
+    let prefix = prefix.trim();
+    let prefix = match prefix.parse::<u32>() {
+        Ok(p) if p <= 32 => p,
+        Ok(_) => return None,
+        Err(_) => mask_prefix(prefix.parse().ok()?)?,
+    };
+    Some((addr, prefix))
+}
+
+/// Network address of the block of length `prefix` containing `ip`.
+pub fn network_of(ip: Ipv4Addr, prefix: u32) -> Ipv4Addr {
+    Ipv4Addr::from(u32::from(ip) & prefix_mask(prefix))
+}
+
+/// Number of addresses in a block of length `prefix`.
+pub fn block_size(prefix: u32) -> u64 {
+    1u64 << (32 - prefix)
+}
+
+/// A count with thousands separators, such as `16,777,214`.
+pub fn group_digits(n: u64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// `ip` moved by `delta` addresses, or `None` past either end of the
+/// address space.
+pub fn offset(ip: Ipv4Addr, delta: i64) -> Option<Ipv4Addr> {
+    let moved = i64::from(u32::from(ip)).checked_add(delta)?;
+    u32::try_from(moved).ok().map(Ipv4Addr::from)
+}
+
+/// A normalized IPv4 block: `network` never has host bits set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cidr {
+    pub network: Ipv4Addr,
+    pub prefix: u32,
+}
+
+impl Cidr {
+    pub fn new(addr: Ipv4Addr, prefix: u32) -> Self {
+        Self {
+            network: network_of(addr, prefix),
+            prefix,
+        }
+    }
+
+    /// Like [`parse_cidr`], but a bare address is taken as a /32.
+    pub fn parse(input: &str) -> Option<Self> {
+        match parse_cidr(input) {
+            Some((addr, prefix)) => Some(Self::new(addr, prefix)),
+            None => input.trim().parse().ok().map(|addr| Self::new(addr, 32)),
+        }
+    }
+
+    pub fn first(&self) -> u32 {
+        u32::from(self.network)
+    }
+
+    pub fn last(&self) -> u32 {
+        self.first() | !prefix_mask(self.prefix)
+    }
+
+    pub fn size(&self) -> u64 {
+        block_size(self.prefix)
+    }
+
+    pub fn contains(&self, other: &Cidr) -> bool {
+        self.prefix <= other.prefix && network_of(other.network, self.prefix) == self.network
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_sizes_reach_the_whole_space() {
+        assert_eq!(block_size(0), 1 << 32);
+        assert_eq!(block_size(24), 256);
+        assert_eq!(block_size(32), 1);
+    }
+
+    #[test]
+    fn usable_hosts_follow_rfc_3021_for_31_and_32() {
+        assert_eq!(usable_hosts(0), (1 << 32) - 2);
+        assert_eq!(usable_hosts(30), 2);
+        assert_eq!(usable_hosts(31), 2);
+        assert_eq!(usable_hosts(32), 1);
+    }
+
+    #[test]
+    fn digits_group_in_threes() {
+        assert_eq!(group_digits(0), "0");
+        assert_eq!(group_digits(999), "999");
+        assert_eq!(group_digits(1000), "1,000");
+        assert_eq!(group_digits(16_777_214), "16,777,214");
+        assert_eq!(group_digits(4_294_967_296), "4,294,967,296");
+    }
+
+    #[test]
+    fn no_hosts_fit_a_single_address() {
+        assert_eq!(prefix_for_hosts(0), Some(32));
+        assert_eq!(prefix_for_hosts(1), Some(32));
+    }
+
+    #[test]
+    fn host_counts_on_a_block_boundary_use_that_block() {
+        assert_eq!(prefix_for_hosts(2), Some(31));
+        assert_eq!(prefix_for_hosts(6), Some(29));
+        assert_eq!(prefix_for_hosts(254), Some(24));
+        assert_eq!(prefix_for_hosts(255), Some(23));
+        assert_eq!(prefix_for_hosts((1 << 32) - 2), Some(0));
+    }
+
+    #[test]
+    fn more_hosts_than_the_whole_space_do_not_fit() {
+        assert_eq!(prefix_for_hosts((1 << 32) - 1), None);
+        assert_eq!(prefix_for_hosts(u64::MAX), None);
+    }
+
+    #[test]
+    fn subnets_round_up_to_a_power_of_two() {
+        let s = subnets_for_count(24, 5).unwrap();
+        assert_eq!(
+            (s.required, s.borrowed_bits, s.new_prefix, s.subnets),
+            (5, 3, 27, 8)
+        );
+        assert_eq!(s.hosts_per_subnet, 30);
+
+        let s = subnets_for_count(24, 1).unwrap();
+        assert_eq!((s.borrowed_bits, s.new_prefix, s.subnets), (0, 24, 1));
+    }
+
+    #[test]
+    fn subnets_may_borrow_down_to_a_single_address() {
+        let s = subnets_for_count(24, 256).unwrap();
+        assert_eq!((s.new_prefix, s.subnets, s.hosts_per_subnet), (32, 256, 1));
+        let s = subnets_for_count(0, 1 << 32).unwrap();
+        assert_eq!((s.borrowed_bits, s.new_prefix), (32, 32));
+    }
+
+    #[test]
+    fn subnets_cannot_borrow_past_32() {
+        assert!(subnets_for_count(24, 257).is_none());
+        assert!(subnets_for_count(32, 2).is_none());
+        assert!(subnets_for_count(0, (1 << 32) + 1).is_none());
+        assert!(subnets_for_count(24, 0).is_none());
+    }
+}
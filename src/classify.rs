@@ -0,0 +1,183 @@
+use std::{fmt, net::Ipv4Addr};
+
+use crate::{cidr::prefix_mask, i18n::tr};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IpClass {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+impl IpClass {
+    pub fn of(ip: Ipv4Addr) -> Self {
+        match ip.octets()[0] {
+            0..=127 => IpClass::A,
+            128..=191 => IpClass::B,
+            192..=223 => IpClass::C,
+            224..=239 => IpClass::D,
+            _ => IpClass::E,
+        }
+    }
+
+    /// The prefix length classful routing implied for the class.
+    pub fn default_prefix(self) -> u32 {
+        match self {
+            IpClass::A => 8,
+            IpClass::B => 16,
+            IpClass::C => 24,
+            IpClass::D | IpClass::E => 32,
+        }
+    }
+}
+
+impl fmt::Display for IpClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            IpClass::A => tr!("class-a"),
+            IpClass::B => tr!("class-b"),
+            IpClass::C => tr!("class-c"),
+            IpClass::D => tr!("class-d"),
+            IpClass::E => tr!("class-e"),
+        };
+        f.write_str(&name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scope {
+    ThisNetwork,
+    Private,
+    SharedCgnat,
+    Loopback,
+    LinkLocal,
+    ProtocolAssignments,
+    Documentation,
+    Relay6to4,
+    Benchmarking,
+    Multicast,
+    Reserved,
+    Broadcast,
+    Public,
+}
+
+impl Scope {
+    /// Scopes that should never host a production network.
+    pub fn is_hazardous(self) -> bool {
+        matches!(
+            self,
+            Scope::ThisNetwork
+                | Scope::Loopback
+                | Scope::LinkLocal
+                | Scope::Benchmarking
+                | Scope::Multicast
+                | Scope::Reserved
+                | Scope::Broadcast
+        )
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Scope::ThisNetwork => tr!("scope-this-network"),
+            Scope::Private => tr!("scope-private"),
+            Scope::SharedCgnat => tr!("scope-shared-cgnat"),
+            Scope::Loopback => tr!("scope-loopback"),
+            Scope::LinkLocal => tr!("scope-link-local"),
+            Scope::ProtocolAssignments => tr!("scope-protocol-assignments"),
+            Scope::Documentation => tr!("scope-documentation"),
+            Scope::Relay6to4 => tr!("scope-relay-6to4"),
+            Scope::Benchmarking => tr!("scope-benchmarking"),
+            Scope::Multicast => tr!("scope-multicast"),
+            Scope::Reserved => tr!("scope-reserved"),
+            Scope::Broadcast => tr!("scope-broadcast"),
+            Scope::Public => tr!("scope-public"),
+        };
+        f.write_str(&name)
+    }
+}
+
+/// An entry of the IANA IPv4 special-purpose address registry.
+pub struct SpecialRange {
+    pub network: Ipv4Addr,
+    pub prefix: u32,
+    pub scope: Scope,
+    pub rfc: &'static str,
+}
+
+impl SpecialRange {
+    pub fn contains(&self, ip: Ipv4Addr) -> bool {
+        let mask = prefix_mask(self.prefix);
+        u32::from(ip) & mask == u32::from(self.network)
+    }
+
+    pub fn last(&self) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.network) | !prefix_mask(self.prefix))
+    }
+
+    pub fn overlaps(&self, first: Ipv4Addr, last: Ipv4Addr) -> bool {
+        self.network <= last && first <= self.last()
+    }
+}
+
+const fn range(
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    prefix: u32,
+    scope: Scope,
+    rfc: &'static str,
+) -> SpecialRange {
+    SpecialRange {
+        network: Ipv4Addr::new(a, b, c, d),
+        prefix,
+        scope,
+        rfc,
+    }
+}
+
+pub const SPECIAL_USE: &[SpecialRange] = &[
+    range(0, 0, 0, 0, 8, Scope::ThisNetwork, "RFC 791"),
+    range(10, 0, 0, 0, 8, Scope::Private, "RFC 1918"),
+    range(100, 64, 0, 0, 10, Scope::SharedCgnat, "RFC 6598"),
+    range(127, 0, 0, 0, 8, Scope::Loopback, "RFC 1122"),
+    range(169, 254, 0, 0, 16, Scope::LinkLocal, "RFC 3927"),
+    range(172, 16, 0, 0, 12, Scope::Private, "RFC 1918"),
+    range(192, 0, 0, 0, 24, Scope::ProtocolAssignments, "RFC 6890"),
+    range(192, 0, 2, 0, 24, Scope::Documentation, "RFC 5737"),
+    range(192, 88, 99, 0, 24, Scope::Relay6to4, "RFC 7526"),
+    range(192, 168, 0, 0, 16, Scope::Private, "RFC 1918"),
+    range(198, 18, 0, 0, 15, Scope::Benchmarking, "RFC 2544"),
+    range(198, 51, 100, 0, 24, Scope::Documentation, "RFC 5737"),
+    range(203, 0, 113, 0, 24, Scope::Documentation, "RFC 5737"),
+    range(224, 0, 0, 0, 4, Scope::Multicast, "RFC 5771"),
+    range(240, 0, 0, 0, 4, Scope::Reserved, "RFC 1112"),
+    range(255, 255, 255, 255, 32, Scope::Broadcast, "RFC 919"),
+];
+
+/// Returns the most specific registry entry covering `ip`, if any.
+pub fn lookup(ip: Ipv4Addr) -> Option<&'static SpecialRange> {
+    SPECIAL_USE
+        .iter()
+        .filter(|r| r.contains(ip))
+        .max_by_key(|r| r.prefix)
+}
+
+pub fn classify(ip: Ipv4Addr) -> (Scope, Option<&'static str>) {
+    match lookup(ip) {
+        Some(r) => (r.scope, Some(r.rfc)),
+        None => (Scope::Public, None),
+    }
+}
+
+/// Hazardous registry entries overlapping the range `first..=last`.
+pub fn hazards(first: Ipv4Addr, last: Ipv4Addr) -> Vec<&'static SpecialRange> {
+    SPECIAL_USE
+        .iter()
+        .filter(|r| r.scope.is_hazardous() && r.overlaps(first, last))
+        .collect()
+}
@@ -0,0 +1,46 @@
+use crate::{cidr::Cidr, input::Input, overlap, range, search::ListFilter};
+
+/// Tidies a pasted list of blocks: sorted, deduplicated and merged.
+pub struct CleanupTool {
+    pub list_input: Input,
+    pub typing: bool,
+    /// Entries parsed from the list, before any cleanup.
+    pub entries: usize,
+    /// Distinct blocks, sorted.
+    pub unique: Vec<Cidr>,
+    pub merged: Vec<Cidr>,
+    pub invalid: Vec<String>,
+    pub scroll: usize,
+    pub filter: ListFilter,
+}
+
+impl CleanupTool {
+    pub fn new() -> Self {
+        Self {
+            list_input: Input::default(),
+            typing: false,
+            entries: 0,
+            unique: Vec::new(),
+            merged: Vec::new(),
+            invalid: Vec::new(),
+            scroll: 0,
+            filter: ListFilter::new(),
+        }
+    }
+
+    pub fn clean(&mut self) {
+        let (mut blocks, invalid) = overlap::parse_list(&self.list_input);
+        self.entries = blocks.len();
+        blocks.sort();
+        blocks.dedup();
+        self.merged = range::merge(&blocks);
+        self.unique = blocks;
+        self.invalid = invalid;
+        self.scroll = 0;
+    }
+
+    /// Addresses the list covers, counting overlaps once.
+    pub fn covered(&self) -> u64 {
+        self.merged.iter().map(Cidr::size).sum()
+    }
+}
@@ -0,0 +1,117 @@
+use std::{fmt, net::Ipv4Addr, ops::RangeInclusive};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{cidr, i18n::tr};
+
+/// A cloud provider whose subnets lose more than the network and broadcast
+/// addresses.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cloud {
+    Aws,
+    Azure,
+    Gcp,
+}
+
+/// An address a provider keeps for itself, and what for.
+pub struct Reserved {
+    pub address: Ipv4Addr,
+    pub purpose: Purpose,
+}
+
+#[derive(Clone, Copy)]
+pub enum Purpose {
+    Network,
+    VpcRouter,
+    DefaultGateway,
+    Dns,
+    AzureDns,
+    FutureUse,
+    Broadcast,
+}
+
+impl Cloud {
+    /// Cycles off -> AWS -> Azure -> GCP -> off.
+    pub fn next(cloud: Option<Cloud>) -> Option<Cloud> {
+        match cloud {
+            None => Some(Cloud::Aws),
+            Some(Cloud::Aws) => Some(Cloud::Azure),
+            Some(Cloud::Azure) => Some(Cloud::Gcp),
+            Some(Cloud::Gcp) => None,
+        }
+    }
+
+    /// Subnet sizes the provider accepts.
+    pub fn allowed_prefixes(self) -> RangeInclusive<u32> {
+        match self {
+            Cloud::Aws => 16..=28,
+            Cloud::Azure | Cloud::Gcp => 8..=29,
+        }
+    }
+
+    pub fn reserved(self, network: Ipv4Addr, prefix: u32) -> Vec<Reserved> {
+        let first = u32::from(cidr::network_of(network, prefix));
+        let last = first | !cidr::prefix_mask(prefix);
+        let at = |address: u32, purpose| Reserved {
+            address: Ipv4Addr::from(address),
+            purpose,
+        };
+        let mut reserved = match self {
+            Cloud::Aws => vec![
+                at(first, Purpose::Network),
+                at(first.wrapping_add(1), Purpose::VpcRouter),
+                at(first.wrapping_add(2), Purpose::Dns),
+                at(first.wrapping_add(3), Purpose::FutureUse),
+                at(last, Purpose::Broadcast),
+            ],
+            Cloud::Azure => vec![
+                at(first, Purpose::Network),
+                at(first.wrapping_add(1), Purpose::DefaultGateway),
+                at(first.wrapping_add(2), Purpose::AzureDns),
+                at(first.wrapping_add(3), Purpose::AzureDns),
+                at(last, Purpose::Broadcast),
+            ],
+            Cloud::Gcp => vec![
+                at(first, Purpose::Network),
+                at(first.wrapping_add(1), Purpose::DefaultGateway),
+                at(last.wrapping_sub(1), Purpose::FutureUse),
+                at(last, Purpose::Broadcast),
+            ],
+        };
+        // Blocks smaller than the provider allows can't fit every entry.
+        reserved.retain(|r| (first..=last).contains(&u32::from(r.address)));
+        reserved.sort_by_key(|r| r.address);
+        reserved.dedup_by_key(|r| r.address);
+        reserved
+    }
+
+    /// Hosts left for instances once the provider's addresses are taken.
+    pub fn usable_hosts(self, network: Ipv4Addr, prefix: u32) -> u64 {
+        cidr::block_size(prefix).saturating_sub(self.reserved(network, prefix).len() as u64)
+    }
+}
+
+impl fmt::Display for Cloud {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Cloud::Aws => "AWS",
+            Cloud::Azure => "Azure",
+            Cloud::Gcp => "GCP",
+        })
+    }
+}
+
+impl fmt::Display for Purpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Purpose::Network => tr!("cloud-network"),
+            Purpose::VpcRouter => tr!("cloud-vpc-router"),
+            Purpose::DefaultGateway => tr!("cloud-default-gateway"),
+            Purpose::Dns => tr!("cloud-dns"),
+            Purpose::AzureDns => tr!("cloud-azure-dns"),
+            Purpose::FutureUse => tr!("cloud-future-use"),
+            Purpose::Broadcast => tr!("cloud-broadcast"),
+        };
+        f.write_str(&name)
+    }
+}
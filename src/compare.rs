@@ -0,0 +1,106 @@
+use std::net::Ipv4Addr;
+
+use crate::{
+    cidr::{self, Cidr},
+    i18n::tr,
+};
+
+/// One derived value of the pinned and the current calculation.
+pub struct Field {
+    pub name: String,
+    pub pinned: String,
+    pub current: String,
+}
+
+impl Field {
+    pub fn differs(&self) -> bool {
+        self.pinned != self.current
+    }
+}
+
+/// The pinned block's derived values beside the current block's, `-`
+/// while nothing is calculated.
+pub fn fields(pinned: Cidr, current: Option<Cidr>) -> Vec<Field> {
+    let values = |block: Cidr| {
+        let mask = cidr::prefix_mask(block.prefix);
+        // RFC 3021: /31 and /32 have no network or broadcast address.
+        let (first, last) = if block.prefix >= 31 {
+            (block.first(), block.last())
+        } else {
+            (block.first() + 1, block.last() - 1)
+        };
+        [
+            block.to_string(),
+            Ipv4Addr::from(mask).to_string(),
+            format!("/{}", block.prefix),
+            block.network.to_string(),
+            Ipv4Addr::from(block.last()).to_string(),
+            format!("{} - {}", Ipv4Addr::from(first), Ipv4Addr::from(last)),
+            cidr::group_digits(cidr::usable_hosts(block.prefix)),
+            cidr::group_digits(block.size()),
+        ]
+    };
+    let names = [
+        tr!("field-cidr"),
+        tr!("column-mask"),
+        tr!("field-prefix"),
+        tr!("result-network"),
+        tr!("result-broadcast"),
+        tr!("result-host-range"),
+        tr!("result-host-count"),
+        tr!("column-block"),
+    ];
+    let current = current.map(values);
+    names
+        .into_iter()
+        .zip(values(pinned))
+        .enumerate()
+        .map(|(i, (name, pinned))| Field {
+            name,
+            pinned,
+            current: current
+                .as_ref()
+                .map_or_else(|| "-".to_string(), |c| c[i].clone()),
+        })
+        .collect()
+}
+
+/// How the two blocks' addresses relate. CIDR blocks either nest or are
+/// disjoint; disjoint halves of one larger block are called out, as that
+/// is the choice between one block and two smaller ones.
+pub fn relation(pinned: Cidr, current: Cidr) -> String {
+    if pinned == current {
+        tr!("compare-identical")
+    } else if pinned.contains(&current) {
+        tr!(
+            "compare-pinned-contains",
+            share = share(current, pinned),
+            block = pinned.to_string()
+        )
+    } else if current.contains(&pinned) {
+        tr!(
+            "compare-current-contains",
+            share = share(pinned, current),
+            block = current.to_string()
+        )
+    } else if pinned.prefix == current.prefix
+        && pinned.prefix > 0
+        && Cidr::new(pinned.network, pinned.prefix - 1)
+            == Cidr::new(current.network, current.prefix - 1)
+    {
+        tr!(
+            "compare-halves",
+            block = Cidr::new(pinned.network, pinned.prefix - 1).to_string()
+        )
+    } else {
+        tr!("compare-disjoint")
+    }
+}
+
+/// What fraction of `outer` the nested `inner` block covers, as `1/n`.
+fn share(inner: Cidr, outer: Cidr) -> String {
+    format!(
+        "1/{}",
+        cidr::group_digits(1u64 << (inner.prefix - outer.prefix))
+    )
+}
@@ -0,0 +1,47 @@
+use std::net::Ipv4Addr;
+
+use crate::cidr::prefix_mask;
+
+/// Prefixes offered when completing the subnet field.
+const COMMON_PREFIXES: &[u32] = &[8, 12, 16, 20, 22, 23, 24, 25, 26, 27, 28, 29, 30, 32];
+
+pub struct Suggestion {
+    pub value: String,
+    pub detail: String,
+}
+
+/// Common masks whose dotted form starts with `typed`, or whose prefix
+/// does when `typed` starts with `/`.
+pub fn masks(typed: &str) -> Vec<Suggestion> {
+    let typed = typed.trim();
+    COMMON_PREFIXES
+        .iter()
+        .map(|&prefix| Suggestion {
+            value: Ipv4Addr::from(prefix_mask(prefix)).to_string(),
+            detail: format!("/{}", prefix),
+        })
+        .filter(|s| s.value != typed && (s.value.starts_with(typed) || s.detail.starts_with(typed)))
+        .collect()
+}
+
+/// Addresses from `history`, most recent first, that start with `typed`.
+pub fn addresses(
+    typed: &str,
+    history: impl Iterator<Item = (Ipv4Addr, Ipv4Addr)>,
+) -> Vec<Suggestion> {
+    let typed = typed.trim();
+    let mut suggestions: Vec<Suggestion> = Vec::new();
+    for (ip, subnet) in history {
+        let value = ip.to_string();
+        if value != typed
+            && value.starts_with(typed)
+            && !suggestions.iter().any(|s| s.value == value)
+        {
+            suggestions.push(Suggestion {
+                value,
+                detail: subnet.to_string(),
+            });
+        }
+    }
+    suggestions
+}
@@ -0,0 +1,128 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    net::IpAddr,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    i18n::tr,
+    keymap::{Action, Context, KeyBinding, Keymap},
+};
+
+/// Settings read from `config.toml`, e.g.
+///
+/// ```toml
+/// language = "id"
+/// no_color = true
+/// nameserver = "192.0.2.53"
+///
+/// [keymap]
+/// preset = "vim"
+///
+/// [keymap.keys.calculator]
+/// focus-ip = "I"
+/// calculate = ["enter", "f5"]
+///
+/// [geoip]
+/// location = "/usr/share/GeoIP/GeoLite2-City.mmdb"
+/// asn = "/usr/share/GeoIP/GeoLite2-ASN.mmdb"
+/// ```
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct Config {
+    /// `en` or `id`; unset follows the locale.
+    pub language: Option<String>,
+    /// Draw with bold and reverse video instead of color, as `--no-color`
+    /// or `NO_COLOR` do.
+    pub no_color: bool,
+    /// Where PTR lookups go; unset uses the first in `/etc/resolv.conf`.
+    pub nameserver: Option<IpAddr>,
+    pub keymap: KeymapConfig,
+    pub geoip: GeoIpConfig,
+}
+
+/// MaxMind database paths. Both are optional.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct GeoIpConfig {
+    /// A City or Country database.
+    pub location: Option<PathBuf>,
+    pub asn: Option<PathBuf>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct KeymapConfig {
+    pub preset: Option<String>,
+    pub keys: HashMap<String, HashMap<String, Keys>>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum Keys {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// `$XDG_CONFIG_HOME/subnet_calculator_tui/config.toml`, falling back to
+/// `~/.config` and finally the working directory.
+pub fn config_path() -> PathBuf {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("subnet_calculator_tui").join("config.toml")
+}
+
+/// Reads the config file. A missing file is an empty config.
+pub fn load() -> Result<Config, String> {
+    let path = config_path();
+    match fs::read_to_string(&path) {
+        Ok(contents) => {
+            toml::from_str(&contents).map_err(|err| format!("{}: {}", path.display(), err))
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+        Err(err) => Err(format!("{}: {}", path.display(), err)),
+    }
+}
+
+impl KeymapConfig {
+    /// Builds the keymap from the chosen preset and overrides, collecting
+    /// a message for every entry that could not be applied.
+    pub fn build(&self) -> (Keymap, Vec<String>) {
+        let mut errors = Vec::new();
+        let preset = self.preset.as_deref().unwrap_or("default");
+        let mut keymap = Keymap::preset(preset).unwrap_or_else(|| {
+            errors.push(tr!("error-config-preset", preset = preset));
+            Keymap::default_preset()
+        });
+        for (context_name, actions) in &self.keys {
+            let Some(context) = Context::from_name(context_name) else {
+                errors.push(tr!("error-config-context", context = context_name));
+                continue;
+            };
+            for (action_name, keys) in actions {
+                let Some(action) = Action::from_name(action_name) else {
+                    errors.push(tr!("error-config-action", action = action_name));
+                    continue;
+                };
+                let keys = match keys {
+                    Keys::One(key) => std::slice::from_ref(key),
+                    Keys::Many(keys) => keys.as_slice(),
+                };
+                let mut parsed = Vec::new();
+                for key in keys {
+                    match KeyBinding::parse(key) {
+                        Some(binding) => parsed.push(binding),
+                        None => errors.push(tr!("error-config-key", key = key)),
+                    }
+                }
+                keymap.bind(context, action, parsed);
+            }
+        }
+        (keymap, errors)
+    }
+}
@@ -0,0 +1,41 @@
+use std::net::Ipv4Addr;
+
+use crate::cidr;
+
+pub const DEFAULT_STATIC_PERCENT: u32 = 20;
+
+/// A suggested DHCP scope: the first host as gateway, then a block kept
+/// out of the pool for static assignments, then the pool itself.
+pub struct DhcpScope {
+    pub gateway: Ipv4Addr,
+    pub excluded: Option<(Ipv4Addr, Ipv4Addr)>,
+    pub pool: (Ipv4Addr, Ipv4Addr),
+    pub static_percent: u32,
+}
+
+/// Splits the hosts after the gateway into `static_percent` statics and a
+/// pool. `None` when the subnet leaves no host for the pool.
+pub fn suggest(network: Ipv4Addr, prefix: u32, static_percent: u32) -> Option<DhcpScope> {
+    if prefix >= 31 {
+        return None;
+    }
+    let first = u32::from(cidr::network_of(network, prefix)) + 1;
+    let hosts = cidr::usable_hosts(prefix) as u32;
+    let remaining = hosts.checked_sub(1).filter(|&n| n > 0)?;
+    let statics = (u64::from(remaining) * u64::from(static_percent.min(100)) / 100) as u32;
+    let pool = remaining - statics;
+    if pool == 0 {
+        return None;
+    }
+    let pool_first = first + 1 + statics;
+    Some(DhcpScope {
+        gateway: Ipv4Addr::from(first),
+        excluded: (statics > 0)
+            .then(|| (Ipv4Addr::from(first + 1), Ipv4Addr::from(first + statics))),
+        pool: (
+            Ipv4Addr::from(pool_first),
+            Ipv4Addr::from(pool_first + pool - 1),
+        ),
+        static_percent,
+    })
+}
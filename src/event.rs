@@ -0,0 +1,59 @@
+use std::{
+    io,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{key::KeyEvent, tui};
+
+/// Everything the app reacts to.
+// Termion reports neither pastes nor resizes.
+#[cfg_attr(not(feature = "crossterm"), allow(dead_code))]
+pub enum Event {
+    Key(KeyEvent),
+    Paste(String),
+    /// The terminal changed to this width and height.
+    Resize(u16, u16),
+    /// Sent every tick rate so background work is collected and toasts
+    /// expire even when no keys are pressed.
+    Tick,
+}
+
+/// Reads terminal events and counts ticks on threads of their own,
+/// interleaving both on one channel.
+pub struct Events {
+    receiver: Receiver<io::Result<Event>>,
+}
+
+impl Events {
+    pub fn new(tick_rate: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let input = sender.clone();
+        thread::spawn(move || {
+            for event in tui::events() {
+                if input.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+        thread::spawn(move || {
+            let mut next_tick = Instant::now() + tick_rate;
+            loop {
+                thread::sleep(next_tick.saturating_duration_since(Instant::now()));
+                next_tick += tick_rate;
+                if sender.send(Ok(Event::Tick)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { receiver }
+    }
+
+    /// Blocks until the next event.
+    pub fn next(&self) -> io::Result<Event> {
+        self.receiver
+            .recv()
+            .map_err(|_| io::Error::other("the event thread stopped"))?
+    }
+}
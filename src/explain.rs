@@ -0,0 +1,229 @@
+use std::net::Ipv4Addr;
+
+use crate::{
+    cidr::{self, Subnetting},
+    classify::IpClass,
+    i18n::tr,
+};
+
+/// Steps every worked solution has, whatever the block.
+pub const STEPS: usize = 5;
+
+/// An address drawn bit by bit, its network, borrowed and host bits told
+/// apart.
+pub struct BitRow {
+    pub label: String,
+    pub addr: Ipv4Addr,
+    pub prefix: u32,
+    /// Where bits borrowed for subnets start; equal to `prefix` when none
+    /// are.
+    pub borrowed_from: u32,
+}
+
+impl BitRow {
+    fn new(label: String, addr: Ipv4Addr, prefix: u32) -> Self {
+        Self {
+            label,
+            addr,
+            prefix,
+            borrowed_from: prefix,
+        }
+    }
+}
+
+/// One stage of the worked solution.
+pub struct Step {
+    pub title: String,
+    pub rows: Vec<BitRow>,
+    /// The operator combining the rows above the last one into it, drawn
+    /// on a rule before the result.
+    pub operator: Option<&'static str>,
+    pub notes: Vec<String>,
+}
+
+/// The calculation of `ip`/`prefix` worked out the way it is taught: the
+/// network by AND, the broadcast by OR with the inverted mask, then the
+/// host range and the counts from host and borrowed bits.
+pub fn steps(ip: Ipv4Addr, prefix: u32, plan: Option<&Subnetting>) -> Vec<Step> {
+    let mask = Ipv4Addr::from(cidr::prefix_mask(prefix));
+    let wildcard = Ipv4Addr::from(!cidr::prefix_mask(prefix));
+    let network = Ipv4Addr::from(u32::from(ip) & u32::from(mask));
+    let broadcast = Ipv4Addr::from(u32::from(network) | u32::from(wildcard));
+    vec![
+        network_step(ip, mask, network, prefix),
+        broadcast_step(network, wildcard, broadcast, prefix),
+        range_step(network, broadcast, prefix),
+        hosts_step(mask, prefix),
+        subnets_step(ip, prefix, plan),
+    ]
+}
+
+fn network_step(ip: Ipv4Addr, mask: Ipv4Addr, network: Ipv4Addr, prefix: u32) -> Step {
+    let mut notes = vec![tr!("explain-and-rule")];
+    notes.extend((0..4).map(|i| {
+        tr!(
+            "explain-octet-and",
+            n = i + 1,
+            ip = ip.octets()[i],
+            mask = mask.octets()[i],
+            result = network.octets()[i]
+        )
+    }));
+    Step {
+        title: tr!("explain-network"),
+        rows: vec![
+            BitRow::new(tr!("binary-ip"), ip, prefix),
+            BitRow::new(tr!("binary-mask"), mask, prefix),
+            BitRow::new(tr!("binary-network"), network, prefix),
+        ],
+        operator: Some("AND"),
+        notes,
+    }
+}
+
+fn broadcast_step(network: Ipv4Addr, wildcard: Ipv4Addr, broadcast: Ipv4Addr, prefix: u32) -> Step {
+    let mut notes = vec![tr!("explain-or-rule")];
+    notes.extend((0..4).map(|i| {
+        tr!(
+            "explain-octet-or",
+            n = i + 1,
+            network = network.octets()[i],
+            wildcard = wildcard.octets()[i],
+            result = broadcast.octets()[i]
+        )
+    }));
+    Step {
+        title: tr!("explain-broadcast"),
+        rows: vec![
+            BitRow::new(tr!("binary-network"), network, prefix),
+            BitRow::new(tr!("column-wildcard"), wildcard, prefix),
+            BitRow::new(tr!("binary-broadcast"), broadcast, prefix),
+        ],
+        operator: Some("OR"),
+        notes,
+    }
+}
+
+fn range_step(network: Ipv4Addr, broadcast: Ipv4Addr, prefix: u32) -> Step {
+    // RFC 3021: /31 and /32 have no network or broadcast address.
+    let (first, last, notes) = if prefix >= 31 {
+        (
+            network,
+            broadcast,
+            vec![tr!("explain-range-rfc3021", prefix = prefix)],
+        )
+    } else {
+        let first = Ipv4Addr::from(u32::from(network) + 1);
+        let last = Ipv4Addr::from(u32::from(broadcast) - 1);
+        (
+            first,
+            last,
+            vec![
+                tr!("explain-first", first = first),
+                tr!("explain-last", last = last),
+            ],
+        )
+    };
+    Step {
+        title: tr!("explain-range"),
+        rows: vec![
+            BitRow::new(tr!("explain-first-host"), first, prefix),
+            BitRow::new(tr!("explain-last-host"), last, prefix),
+        ],
+        operator: None,
+        notes,
+    }
+}
+
+fn hosts_step(mask: Ipv4Addr, prefix: u32) -> Step {
+    let bits = 32 - prefix;
+    let addresses = cidr::group_digits(cidr::block_size(prefix));
+    let hosts = cidr::group_digits(cidr::usable_hosts(prefix));
+    let usable = if prefix >= 31 {
+        tr!("explain-usable-rfc3021", hosts = hosts)
+    } else {
+        tr!(
+            "explain-usable",
+            addresses = addresses.clone(),
+            hosts = hosts
+        )
+    };
+    Step {
+        title: tr!("explain-hosts"),
+        rows: vec![BitRow::new(tr!("binary-mask"), mask, prefix)],
+        operator: None,
+        notes: vec![
+            tr!("explain-host-bits", prefix = prefix, bits = bits),
+            tr!("explain-addresses", bits = bits, addresses = addresses),
+            usable,
+        ],
+    }
+}
+
+/// Borrowed bits come from the split when subnets are asked for, and
+/// otherwise from the prefix's distance to the classful default.
+fn subnets_step(ip: Ipv4Addr, prefix: u32, plan: Option<&Subnetting>) -> Step {
+    let title = tr!("explain-subnets");
+    let (from, to, count) = match plan {
+        Some(plan) => (
+            prefix,
+            plan.new_prefix,
+            tr!(
+                "explain-subnet-count",
+                bits = plan.borrowed_bits,
+                subnets = cidr::group_digits(plan.subnets),
+                required = cidr::group_digits(plan.required)
+            ),
+        ),
+        None => {
+            let class = IpClass::of(ip).default_prefix();
+            if prefix <= class {
+                return Step {
+                    title,
+                    rows: vec![BitRow::new(
+                        tr!("binary-mask"),
+                        Ipv4Addr::from(cidr::prefix_mask(prefix)),
+                        prefix,
+                    )],
+                    operator: None,
+                    notes: vec![tr!("explain-no-borrowed")],
+                };
+            }
+            let bits = prefix - class;
+            (
+                class,
+                prefix,
+                tr!(
+                    "explain-classful-count",
+                    bits = bits,
+                    subnets = cidr::group_digits(1 << bits),
+                    class = class
+                ),
+            )
+        }
+    };
+    let bits = to - from;
+    let row = |label, prefix| BitRow {
+        label,
+        addr: Ipv4Addr::from(cidr::prefix_mask(prefix)),
+        prefix: to,
+        borrowed_from: from,
+    };
+    Step {
+        title,
+        rows: vec![
+            row(tr!("explain-old-mask"), from),
+            row(tr!("explain-new-mask"), to),
+        ],
+        operator: None,
+        notes: vec![
+            tr!("explain-borrowed", bits = bits, from = from, to = to),
+            count,
+            tr!(
+                "explain-subnet-hosts",
+                bits = 32 - to,
+                hosts = cidr::group_digits(cidr::usable_hosts(to))
+            ),
+        ],
+    }
+}
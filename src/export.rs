@@ -0,0 +1,389 @@
+use std::{fs, io, net::Ipv4Addr, path::PathBuf};
+
+use crate::{
+    cidr::{self, Subnetting},
+    dhcp::DhcpScope,
+    hosts::Labels,
+    i18n, rdns,
+};
+
+/// Subnets a split lists one line each for; Terraform's `count` still
+/// covers the rest.
+const MAX_LISTED_SUBNETS: u64 = 4096;
+
+/// The values templates can refer to as `{name}`.
+pub struct SubnetInfo {
+    pub network: Ipv4Addr,
+    pub prefix: u32,
+    pub mask: Ipv4Addr,
+    pub wildcard: Ipv4Addr,
+    pub broadcast: Ipv4Addr,
+    pub first: Ipv4Addr,
+    pub last: Ipv4Addr,
+    /// The suggested DHCP gateway, or the first host without a scope.
+    pub gateway: Ipv4Addr,
+    pub statics: Option<(Ipv4Addr, Ipv4Addr)>,
+    pub pool: Option<(Ipv4Addr, Ipv4Addr)>,
+    /// Host bits borrowed for the split; 0 when the block isn't split.
+    pub split_bits: u32,
+    /// Subnets taken from the split, 1 when the block isn't split.
+    pub split_count: u64,
+    /// Labelled addresses inside the block, in address order.
+    pub labels: Vec<(Ipv4Addr, String)>,
+    /// The `in-addr.arpa` zones covering the block.
+    pub reverse_zones: Vec<String>,
+}
+
+impl SubnetInfo {
+    pub fn new(
+        network: Ipv4Addr,
+        prefix: u32,
+        dhcp: Option<&DhcpScope>,
+        split: Option<&Subnetting>,
+        labels: &Labels,
+    ) -> Self {
+        let network = cidr::network_of(network, prefix);
+        let mask = cidr::prefix_mask(prefix);
+        let base = u32::from(network);
+        let broadcast = base | !mask;
+        let (first, last) = if prefix >= 31 {
+            (base, broadcast)
+        } else {
+            (base + 1, broadcast - 1)
+        };
+        Self {
+            network,
+            prefix,
+            mask: Ipv4Addr::from(mask),
+            wildcard: Ipv4Addr::from(!mask),
+            broadcast: Ipv4Addr::from(broadcast),
+            first: Ipv4Addr::from(first),
+            last: Ipv4Addr::from(last),
+            gateway: dhcp.map_or(Ipv4Addr::from(first), |d| d.gateway),
+            statics: dhcp.and_then(|d| d.excluded),
+            pool: dhcp.map(|d| d.pool),
+            split_bits: split.map_or(0, |plan| plan.borrowed_bits),
+            split_count: split.map_or(1, |plan| plan.required),
+            labels: labels
+                .range(network..=Ipv4Addr::from(broadcast))
+                .map(|(&host, label)| (host, label.clone()))
+                .collect(),
+            reverse_zones: rdns::reverse_zones(network, prefix),
+        }
+    }
+
+    /// The subnets of the split in order, as CIDR strings.
+    fn subnets(&self) -> impl Iterator<Item = String> + '_ {
+        let new_prefix = self.prefix + self.split_bits;
+        let size = cidr::block_size(new_prefix);
+        (0..self.split_count.min(MAX_LISTED_SUBNETS)).map(move |i| {
+            let network = u64::from(u32::from(self.network)) + i * size;
+            format!("{}/{}", Ipv4Addr::from(network as u32), new_prefix)
+        })
+    }
+
+    fn field(&self, name: &str) -> Option<String> {
+        Some(match name {
+            "cidr" => format!("{}/{}", self.network, self.prefix),
+            "network" => self.network.to_string(),
+            "prefix" => self.prefix.to_string(),
+            "mask" => self.mask.to_string(),
+            "wildcard" => self.wildcard.to_string(),
+            "broadcast" => self.broadcast.to_string(),
+            "first" => self.first.to_string(),
+            "last" => self.last.to_string(),
+            "name" => format!("NET_{}_{}", self.network, self.prefix).replace('.', "_"),
+            "gateway" => self.gateway.to_string(),
+            "static_first" => self.statics?.0.to_string(),
+            "static_last" => self.statics?.1.to_string(),
+            "pool_first" => self.pool?.0.to_string(),
+            "pool_last" => self.pool?.1.to_string(),
+            "split_bits" => self.split_bits.to_string(),
+            "split_count" => self.split_count.to_string(),
+            "reverse_zones" => self.reverse_zones.join(" "),
+            _ => return None,
+        })
+    }
+}
+
+/// Fills `{name}` placeholders from `info`. A line whose placeholders
+/// can't all be filled, such as a static range when there is none, is
+/// left out; a line using `{subnet}` is repeated for each subnet of the
+/// split, one using `{zone}` for each reverse zone, and one using `{host}`
+/// or `{label}` for each labelled host. `{{` and `}}` stand for literal
+/// braces.
+pub fn render(template: &str, info: &SubnetInfo) -> String {
+    let mut out = String::new();
+    for line in template.lines() {
+        let rendered: Vec<String> = if line.contains("{subnet}") {
+            info.subnets()
+                .filter_map(|subnet| render_line(line, info, &[("subnet", subnet)]))
+                .collect()
+        } else if line.contains("{zone}") {
+            info.reverse_zones
+                .iter()
+                .filter_map(|zone| render_line(line, info, &[("zone", zone.clone())]))
+                .collect()
+        } else if line.contains("{host}") || line.contains("{label}") {
+            info.labels
+                .iter()
+                .filter_map(|(host, label)| {
+                    render_line(
+                        line,
+                        info,
+                        &[("host", host.to_string()), ("label", label.clone())],
+                    )
+                })
+                .collect()
+        } else {
+            render_line(line, info, &[]).into_iter().collect()
+        };
+        for line in rendered {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Fills one line, taking the per-line values in `vars` before the
+/// fields of `info`. A brace without a partner is copied as it is.
+fn render_line(line: &str, info: &SubnetInfo, vars: &[(&str, String)]) -> Option<String> {
+    let mut rendered = String::new();
+    let mut rest = line;
+    while let Some(start) = rest.find(['{', '}']) {
+        rendered.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let brace = &tail[..1];
+        let doubled = tail[1..].starts_with(brace);
+        if brace == "}" || doubled {
+            rendered.push_str(brace);
+            rest = &tail[1 + usize::from(doubled)..];
+            continue;
+        }
+        let Some(len) = tail.find('}') else {
+            rendered.push_str(tail);
+            return Some(rendered);
+        };
+        let name = &tail[1..len];
+        let value = match vars.iter().find(|(var, _)| *var == name) {
+            Some((_, value)) => value.clone(),
+            None => info.field(name)?,
+        };
+        rendered.push_str(&value);
+        rest = &tail[len + 1..];
+    }
+    rendered.push_str(rest);
+    Some(rendered)
+}
+
+pub struct ExportFormat {
+    /// Message id of the name shown in the picker.
+    pub id: &'static str,
+    pub extension: &'static str,
+    pub template: &'static str,
+}
+
+impl ExportFormat {
+    pub fn name(&self) -> String {
+        i18n::text(self.id, None)
+    }
+}
+
+pub const FORMATS: &[ExportFormat] = &[
+    ExportFormat {
+        id: "export-format-ios-acl",
+        extension: "ios",
+        template: "access-list 10 remark {host} {label}\n\
+                   access-list 10 permit {network} {wildcard}\n\
+                   ip access-list extended {name}\n permit ip {network} {wildcard} any",
+    },
+    ExportFormat {
+        id: "export-format-ios-route",
+        extension: "ios",
+        template: "ip route {network} {mask} {gateway}",
+    },
+    ExportFormat {
+        id: "export-format-ios-dhcp",
+        extension: "ios",
+        template: "ip dhcp excluded-address {gateway}\n\
+                   ip dhcp excluded-address {static_first} {static_last}\n\
+                   ip dhcp pool {name}\n network {network} {mask}\n default-router {gateway}",
+    },
+    ExportFormat {
+        id: "export-format-nftables",
+        extension: "nft",
+        template: "table inet filter {{\n    chain forward {{\n        ip saddr {cidr} accept\n        ip daddr {cidr} accept\n    }}\n}}",
+    },
+    ExportFormat {
+        id: "export-format-iptables",
+        extension: "sh",
+        template: "iptables -A FORWARD -s {cidr} -j ACCEPT\niptables -A FORWARD -d {cidr} -j ACCEPT",
+    },
+    ExportFormat {
+        id: "export-format-linux-route",
+        extension: "sh",
+        template: "ip route add {cidr} via {gateway}",
+    },
+    ExportFormat {
+        id: "export-format-bind-zones",
+        extension: "conf",
+        template: "// Reverse zones for {cidr}\n\
+                   zone \"{zone}\" {{ type master; file \"db.{zone}\"; }};",
+    },
+    ExportFormat {
+        id: "export-format-hosts",
+        extension: "hosts",
+        template: "# {cidr}\n{host}\t{label}",
+    },
+    ExportFormat {
+        id: "export-format-terraform",
+        extension: "tf",
+        template: "resource \"aws_subnet\" \"{name}\" {{\n  count      = {split_count}\n  vpc_id     = var.vpc_id\n  cidr_block = cidrsubnet(\"{cidr}\", {split_bits}, count.index)\n}}",
+    },
+    ExportFormat {
+        id: "export-format-ansible",
+        extension: "yml",
+        template: "all:\n  vars:\n    network_cidr: {cidr}\n    network_netmask: {mask}\n    network_gateway: {gateway}\n    subnets:\n      - {subnet}",
+    },
+];
+
+/// The format popup, previewing the selected format.
+pub struct ExportPicker {
+    pub info: SubnetInfo,
+    pub selected: usize,
+}
+
+impl ExportPicker {
+    pub fn new(info: SubnetInfo) -> Self {
+        Self { info, selected: 0 }
+    }
+
+    pub fn preview(&self) -> String {
+        render(FORMATS[self.selected].template, &self.info)
+    }
+
+    /// Writes the selected format to the working directory.
+    pub fn save(&self) -> io::Result<PathBuf> {
+        let format = &FORMATS[self.selected];
+        let path = PathBuf::from(format!(
+            "subnet-{}-{}-{}.{}",
+            self.info.network,
+            self.info.prefix,
+            self.selected + 1,
+            format.extension
+        ));
+        fs::write(&path, self.preview())?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(block: &str, labels: &[(&str, &str)]) -> SubnetInfo {
+        let (network, prefix) = cidr::parse_cidr(block).unwrap();
+        let labels: Labels = labels
+            .iter()
+            .map(|(host, label)| (host.parse().unwrap(), label.to_string()))
+            .collect();
+        SubnetInfo::new(network, prefix, None, None, &labels)
+    }
+
+    fn split(block: &str, subnets: u64) -> SubnetInfo {
+        let (network, prefix) = cidr::parse_cidr(block).unwrap();
+        let plan = cidr::subnets_for_count(prefix, subnets).unwrap();
+        SubnetInfo::new(network, prefix, None, Some(&plan), &Labels::new())
+    }
+
+    fn template(id: &str) -> &'static str {
+        FORMATS.iter().find(|f| f.id == id).unwrap().template
+    }
+
+    #[test]
+    fn doubled_braces_are_literal() {
+        let info = info("10.1.2.0/24", &[]);
+        assert_eq!(
+            render("x {{ a = {cidr} }}\n{{{prefix}}}", &info),
+            "x { a = 10.1.2.0/24 }\n{24}\n"
+        );
+    }
+
+    #[test]
+    fn lines_with_a_missing_field_are_left_out() {
+        let info = info("10.1.2.0/24", &[]);
+        assert_eq!(
+            render(
+                "net {network}\nstatic {static_first}\nbogus {nope}\nend",
+                &info
+            ),
+            "net 10.1.2.0\nend\n"
+        );
+    }
+
+    #[test]
+    fn zone_lines_repeat_for_each_reverse_zone() {
+        let info = info("10.1.2.0/23", &[]);
+        assert_eq!(
+            render("; {cidr}\nzone {zone}", &info),
+            "; 10.1.2.0/23\nzone 2.1.10.in-addr.arpa\nzone 3.1.10.in-addr.arpa\n"
+        );
+    }
+
+    #[test]
+    fn host_lines_repeat_for_each_label_in_the_block() {
+        let labelled = info(
+            "10.1.2.0/24",
+            &[
+                ("10.1.2.9", "db"),
+                ("10.1.3.1", "other"),
+                ("10.1.2.1", "gw"),
+            ],
+        );
+        assert_eq!(
+            render("{host}\t{label}", &labelled),
+            "10.1.2.1\tgw\n10.1.2.9\tdb\n"
+        );
+        assert_eq!(render("{host}", &info("10.1.2.0/24", &[])), "");
+    }
+
+    #[test]
+    fn nftables_keeps_its_braces() {
+        assert_eq!(
+            render(template("export-format-nftables"), &info("10.1.2.0/24", &[])),
+            "table inet filter {\n    chain forward {\n        ip saddr 10.1.2.0/24 accept\n        ip daddr 10.1.2.0/24 accept\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn subnet_lines_repeat_for_each_subnet_taken() {
+        assert_eq!(
+            render("- {subnet}", &split("10.1.0.0/16", 3)),
+            "- 10.1.0.0/18\n- 10.1.64.0/18\n- 10.1.128.0/18\n"
+        );
+        assert_eq!(
+            render("- {subnet}", &info("10.1.2.0/24", &[])),
+            "- 10.1.2.0/24\n"
+        );
+    }
+
+    #[test]
+    fn subnet_lines_stop_at_the_listing_cap() {
+        let info = split("10.0.0.0/8", 1 << 16);
+        let rendered = render("{subnet}", &info);
+        let listed: Vec<&str> = rendered.lines().collect();
+        assert_eq!(listed.len() as u64, MAX_LISTED_SUBNETS);
+        assert_eq!(listed[0], "10.0.0.0/24");
+        assert_eq!(listed.last(), Some(&"10.15.255.0/24"));
+        assert_eq!(render("count = {split_count}", &info), "count = 65536\n");
+    }
+
+    #[test]
+    fn terraform_keeps_its_braces() {
+        assert_eq!(
+            render(template("export-format-terraform"), &split("10.1.0.0/16", 4)),
+            "resource \"aws_subnet\" \"NET_10_1_0_0_16\" {\n  count      = 4\n  vpc_id     = var.vpc_id\n  cidr_block = cidrsubnet(\"10.1.0.0/16\", 2, count.index)\n}\n"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::{fmt, net::Ipv4Addr, path::Path};
+
+use maxminddb::{geoip2, Reader};
+
+use crate::{config::GeoIpConfig, i18n::tr};
+
+/// Offline location and ASN lookups from MaxMind databases (GeoLite2 or
+/// GeoIP2). Either database may be missing; lookups then leave its fields
+/// out.
+#[derive(Default)]
+pub struct GeoIp {
+    /// A City or Country database.
+    location: Option<Reader<Vec<u8>>>,
+    asn: Option<Reader<Vec<u8>>>,
+}
+
+/// What the databases know about one address.
+pub struct GeoRecord {
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub asn: Option<(u32, Option<String>)>,
+}
+
+impl GeoIp {
+    /// Opens the configured databases, with a message for each that
+    /// couldn't be read.
+    pub fn open(config: &GeoIpConfig) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut open = |path: &Option<std::path::PathBuf>| {
+            let path = path.as_deref()?;
+            open_reader(path).map_err(|err| errors.push(err)).ok()
+        };
+        let geoip = Self {
+            location: open(&config.location),
+            asn: open(&config.asn),
+        };
+        (geoip, errors)
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.location.is_some() || self.asn.is_some()
+    }
+
+    /// `None` when neither database has the address, as for private space.
+    pub fn lookup(&self, ip: Ipv4Addr) -> Option<GeoRecord> {
+        let location = self.location.as_ref().and_then(|reader| {
+            reader
+                .lookup(ip.into())
+                .ok()?
+                .decode::<geoip2::City>()
+                .ok()?
+        });
+        let asn = self.asn.as_ref().and_then(|reader| {
+            reader
+                .lookup(ip.into())
+                .ok()?
+                .decode::<geoip2::Asn>()
+                .ok()?
+        });
+        if location.is_none() && asn.is_none() {
+            return None;
+        }
+        Some(GeoRecord {
+            city: location
+                .as_ref()
+                .and_then(|l| l.city.names.english.map(str::to_string)),
+            country: location.as_ref().and_then(|l| {
+                let code = l.country.iso_code?;
+                Some(match l.country.names.english {
+                    Some(name) => format!("{} ({})", name, code),
+                    None => code.to_string(),
+                })
+            }),
+            asn: asn.and_then(|asn| {
+                let number = asn.autonomous_system_number?;
+                Some((
+                    number,
+                    asn.autonomous_system_organization.map(str::to_string),
+                ))
+            }),
+        })
+    }
+}
+
+fn open_reader(path: &Path) -> Result<Reader<Vec<u8>>, String> {
+    Reader::open_readfile(path)
+        .map_err(|err| tr!("error-geoip-open", path = path.display(), error = err))
+}
+
+impl fmt::Display for GeoRecord {
+    /// `Mountain View, United States (US), AS15169 Google LLC`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = self.city.iter().chain(&self.country).cloned().collect();
+        if let Some((number, org)) = &self.asn {
+            parts.push(match org {
+                Some(org) => format!("AS{} {}", number, org),
+                None => format!("AS{}", number),
+            });
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
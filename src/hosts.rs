@@ -0,0 +1,200 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    net::Ipv4Addr,
+};
+
+use crate::{cidr, i18n::tr, input::Input};
+
+/// Hosts a search compares addresses with before giving up, so a query
+/// matching nothing in a huge block doesn't stall the screen.
+const SEARCH_LIMIT: u64 = 65536;
+
+pub enum HostPrompt {
+    Jump,
+    Search,
+    Port,
+    Label,
+}
+
+/// Names or notes for single addresses, such as `.1 = gateway`. Keyed by
+/// address rather than host number so they stay put when the block is
+/// resized.
+pub type Labels = BTreeMap<Ipv4Addr, String>;
+
+/// Usable hosts of a subnet, produced on demand by index so that large
+/// blocks never materialize the whole list.
+pub struct HostList {
+    pub first: u32,
+    pub count: u64,
+    pub selected: u64,
+    pub prompt: Option<HostPrompt>,
+    pub input: Input,
+    pub query: Option<String>,
+    pub message: Option<String>,
+    /// Host indexes picked for a port scan.
+    pub marked: BTreeSet<u64>,
+    /// Rows the table has room for at the terminal's size, used for
+    /// paging.
+    pub page_size: u64,
+}
+
+impl HostList {
+    pub fn for_subnet(network: Ipv4Addr, prefix: u32) -> Self {
+        let network = u32::from(cidr::network_of(network, prefix));
+        let size = cidr::block_size(prefix);
+        // RFC 3021: /31 and /32 have no network or broadcast address.
+        let (first, count) = if prefix >= 31 {
+            (network, size)
+        } else {
+            (network + 1, size - 2)
+        };
+        Self {
+            first,
+            count,
+            selected: 0,
+            prompt: None,
+            input: Input::default(),
+            query: None,
+            message: None,
+            marked: BTreeSet::new(),
+            page_size: 1,
+        }
+    }
+
+    pub fn host(&self, index: u64) -> Ipv4Addr {
+        Ipv4Addr::from(self.first + index as u32)
+    }
+
+    pub fn move_by(&mut self, delta: i64) {
+        let last = self.count.saturating_sub(1) as i64;
+        self.selected = (self.selected as i64 + delta).clamp(0, last) as u64;
+    }
+
+    pub fn page(&mut self, forward: bool) {
+        let step = self.page_size as i64;
+        self.move_by(if forward { step } else { -step });
+    }
+
+    /// Jumps to the 1-based host number `n`.
+    pub fn jump(&mut self, n: u64) {
+        if (1..=self.count).contains(&n) {
+            self.selected = n - 1;
+            self.message = None;
+        } else {
+            self.message = Some(tr!("hosts-out-of-range", count = self.count));
+        }
+    }
+
+    /// Selects the next host after the current one whose address or label
+    /// contains `query`, wrapping around. A complete address jumps
+    /// straight to it. Addresses are compared for the next
+    /// [`SEARCH_LIMIT`] hosts only; labels are few and all searched.
+    pub fn search(&mut self, query: &str, labels: &Labels) {
+        if let Ok(ip) = query.parse::<Ipv4Addr>() {
+            let index = u64::from(u32::from(ip).wrapping_sub(self.first));
+            if index < self.count {
+                self.selected = index;
+                self.message = None;
+                return;
+            }
+        }
+        // How far past the selection host `i` is, 1 for the next host and
+        // `count` for the selected one itself.
+        let distance = |i: u64| (i + self.count - self.selected - 1) % self.count + 1;
+        let lowered = query.to_lowercase();
+        let labelled = labels
+            .iter()
+            .filter(|(_, label)| label.to_lowercase().contains(&lowered))
+            .map(|(&host, _)| u64::from(u32::from(host).wrapping_sub(self.first)))
+            .filter(|&i| i < self.count)
+            .min_by_key(|&i| distance(i));
+        let limit = self.count.min(SEARCH_LIMIT);
+        let mut text = String::with_capacity(15);
+        let addressed = (1..=limit)
+            .map(|step| (self.selected + step) % self.count)
+            .find(|&i| {
+                text.clear();
+                let _ = write!(text, "{}", self.host(i));
+                text.contains(query)
+            });
+        match labelled
+            .into_iter()
+            .chain(addressed)
+            .min_by_key(|&i| distance(i))
+        {
+            Some(i) => {
+                self.selected = i;
+                self.message = None;
+            }
+            None if limit < self.count => {
+                self.message = Some(tr!(
+                    "hosts-no-match-within",
+                    query = query,
+                    limit = cidr::group_digits(limit)
+                ))
+            }
+            None => self.message = Some(tr!("hosts-no-match", query = query)),
+        }
+    }
+
+    pub fn toggle_mark(&mut self) {
+        if !self.marked.remove(&self.selected) {
+            self.marked.insert(self.selected);
+        }
+    }
+
+    /// Hosts a port scan should probe: the marked ones, or every host
+    /// when none are marked.
+    pub fn scan_targets(&self) -> Vec<Ipv4Addr> {
+        if self.marked.is_empty() {
+            (0..self.count).map(|i| self.host(i)).collect()
+        } else {
+            self.marked.iter().map(|&i| self.host(i)).collect()
+        }
+    }
+
+    /// Opens the label prompt on the selected host, filled in with its
+    /// current label for editing.
+    pub fn edit_label(&mut self, labels: &Labels) {
+        let current = labels.get(&self.host(self.selected));
+        self.input.set(current.cloned().unwrap_or_default());
+        self.prompt = Some(HostPrompt::Label);
+    }
+
+    /// Handles Enter in the prompt. Returns the port to scan when the
+    /// port prompt was submitted with a valid one.
+    pub fn submit_prompt(&mut self, labels: &mut Labels) -> Option<u16> {
+        let input = std::mem::take(&mut self.input).to_string();
+        match self.prompt.take() {
+            Some(HostPrompt::Jump) => match input.trim().parse() {
+                Ok(n) => self.jump(n),
+                Err(_) => self.message = Some(tr!("hosts-enter-number")),
+            },
+            Some(HostPrompt::Search) if !input.is_empty() => {
+                self.search(&input, labels);
+                self.query = Some(input);
+            }
+            Some(HostPrompt::Port) => match input.trim().parse() {
+                Ok(port) if port > 0 => return Some(port),
+                _ => self.message = Some(tr!("hosts-enter-port-number")),
+            },
+            // An empty label removes the host's label.
+            Some(HostPrompt::Label) => {
+                let host = self.host(self.selected);
+                match input.trim() {
+                    "" => labels.remove(&host),
+                    label => labels.insert(host, label.to_string()),
+                };
+            }
+            _ => {}
+        }
+        None
+    }
+
+    pub fn search_next(&mut self, labels: &Labels) {
+        if let Some(query) = self.query.clone() {
+            self.search(&query, labels);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::{env, sync::OnceLock};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+/// The catalog chosen at startup. Text looked up before then is English.
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// Languages with a bundle in `locales/`.
+#[derive(Clone, Copy, PartialEq)]
+enum Language {
+    English,
+    Indonesian,
+}
+
+impl Language {
+    /// Matches a config value or POSIX locale such as `id_ID.UTF-8`. `in`
+    /// is the code Indonesian had before ISO 639 renamed it.
+    fn from_tag(tag: &str) -> Option<Self> {
+        let code = tag.split(['_', '-', '.', '@']).next()?.to_lowercase();
+        match code.as_str() {
+            "en" | "c" | "posix" => Some(Language::English),
+            "id" | "in" => Some(Language::Indonesian),
+            _ => None,
+        }
+    }
+
+    fn tag(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Indonesian => "id",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("../locales/en.ftl"),
+            Language::Indonesian => include_str!("../locales/id.ftl"),
+        }
+    }
+}
+
+/// The chosen language's bundle, with English behind it for messages it
+/// doesn't translate yet.
+struct Catalog {
+    bundle: FluentBundle<FluentResource>,
+    fallback: Option<FluentBundle<FluentResource>>,
+}
+
+impl Catalog {
+    fn new(language: Language) -> Self {
+        Self {
+            bundle: bundle(language),
+            fallback: (language != Language::English).then(|| bundle(Language::English)),
+        }
+    }
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let tag: LanguageIdentifier = language.tag().parse().expect("valid language tag");
+    let mut bundle = FluentBundle::new_concurrent(vec![tag]);
+    // Terminals draw Unicode bidi isolation marks as stray characters.
+    bundle.set_use_isolating(false);
+    let resource = FluentResource::try_new(language.source().to_string()).unwrap_or_else(
+        |(resource, errors)| {
+            tracing::warn!(?errors, "locale {} has syntax errors", language.tag());
+            resource
+        },
+    );
+    if let Err(errors) = bundle.add_resource(resource) {
+        tracing::warn!(?errors, "locale {} redefines messages", language.tag());
+    }
+    bundle
+}
+
+/// Picks the language from the config file, or else the first of
+/// `LC_ALL`, `LC_MESSAGES` and `LANG` that is set, as gettext does.
+/// Returns an error for a configured language without a bundle.
+pub fn init(configured: Option<&str>) -> Result<(), String> {
+    let (language, unknown) = match configured {
+        Some(tag) => match Language::from_tag(tag) {
+            Some(language) => (language, None),
+            None => (Language::English, Some(tag)),
+        },
+        None => {
+            let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+                .iter()
+                .filter_map(|name| env::var(name).ok())
+                .find(|value| !value.is_empty());
+            let language = locale.as_deref().and_then(Language::from_tag);
+            (language.unwrap_or(Language::English), None)
+        }
+    };
+    let _ = CATALOG.set(Catalog::new(language));
+    unknown.map_or(Ok(()), |tag| {
+        let mut args = FluentArgs::new();
+        args.set("language", tag);
+        Err(text("error-config-language", Some(&args)))
+    })
+}
+
+/// The message `id` with `args` filled in, or the id itself when no bundle
+/// has it.
+pub fn text(id: &str, args: Option<&FluentArgs>) -> String {
+    lookup(id, args).unwrap_or_else(|| {
+        tracing::warn!("no message {}", id);
+        id.to_string()
+    })
+}
+
+/// The message `id` with `args` filled in, if any bundle has it.
+pub fn lookup(id: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let catalog = CATALOG.get_or_init(|| Catalog::new(Language::English));
+    let bundles = std::iter::once(&catalog.bundle).chain(&catalog.fallback);
+    for bundle in bundles {
+        let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+            continue;
+        };
+        let mut errors = Vec::new();
+        let text = bundle.format_pattern(pattern, args, &mut errors);
+        if !errors.is_empty() {
+            tracing::warn!(?errors, "message {} failed to format", id);
+        }
+        return Some(text.into_owned());
+    }
+    None
+}
+
+/// Translated text: `tr!("id")` or `tr!("id", name = value, ...)`, where
+/// each value is passed to the message as a string.
+macro_rules! tr {
+    ($id:literal) => {
+        $crate::i18n::text($id, None)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value.to_string());)+
+        $crate::i18n::text($id, Some(&args))
+    }};
+}
+pub(crate) use tr;
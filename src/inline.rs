@@ -0,0 +1,192 @@
+use std::{
+    io::{self, BufRead, Write},
+    net::Ipv4Addr,
+};
+
+use crate::{
+    app::{App, Workspace},
+    cidr,
+    export::SubnetInfo,
+    geoip::GeoIp,
+    i18n::tr,
+};
+
+/// Asks for the calculator fields one line at a time and prints the
+/// results as `label: value` lines, for screen readers and transcripts.
+/// Runs until the address prompt gets an empty line or input ends.
+pub fn run() -> io::Result<()> {
+    let mut app = App::new();
+    for toast in app.toasts.drain() {
+        eprintln!("{}", toast.message);
+    }
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut out = io::stdout();
+    writeln!(out, "{}", tr!("inline-intro"))?;
+
+    loop {
+        let mut ws = Workspace::new();
+        let (ip, prefix) = loop {
+            let Some(line) = ask(&mut lines, &mut out, &tr!("inline-ip"))? else {
+                return Ok(());
+            };
+            if line.is_empty() {
+                return Ok(());
+            }
+            if let Some((ip, prefix)) = cidr::parse_cidr(&line) {
+                break (ip, Some(prefix));
+            }
+            match line.parse::<Ipv4Addr>() {
+                Ok(ip) => break (ip, None),
+                Err(_) => writeln!(out, "{}", tr!("inline-bad-ip", input = line))?,
+            }
+        };
+        let prefix = match prefix {
+            Some(prefix) => prefix,
+            None => loop {
+                let Some(line) = ask(&mut lines, &mut out, &tr!("inline-mask"))? else {
+                    return Ok(());
+                };
+                match mask_or_length(&line) {
+                    Some(prefix) => break prefix,
+                    None => writeln!(out, "{}", tr!("inline-bad-mask", input = line))?,
+                }
+            },
+        };
+        ws.ip_input.set(ip.to_string());
+        ws.subnet_input
+            .set(Ipv4Addr::from(cidr::prefix_mask(prefix)).to_string());
+        let Some(hosts) = ask(&mut lines, &mut out, &tr!("inline-hosts"))? else {
+            return Ok(());
+        };
+        ws.hosts_input.set(hosts);
+        let Some(subnets) = ask(&mut lines, &mut out, &tr!("inline-subnets"))? else {
+            return Ok(());
+        };
+        ws.subnets_input.set(subnets);
+        ws.calculate();
+
+        writeln!(out)?;
+        for (label, value) in results(&ws, &app.geoip) {
+            writeln!(out, "{}: {}", label, value)?;
+        }
+        for warning in &ws.warnings {
+            writeln!(out, "{}", tr!("inline-warning", warning = warning))?;
+        }
+        writeln!(out)?;
+    }
+}
+
+/// Prints `prompt` on its own line and reads the answer, trimmed, or
+/// `None` at the end of input.
+fn ask(
+    lines: &mut impl Iterator<Item = io::Result<String>>,
+    out: &mut impl Write,
+    prompt: &str,
+) -> io::Result<Option<String>> {
+    writeln!(out, "{}", prompt)?;
+    out.flush()?;
+    lines
+        .next()
+        .transpose()
+        .map(|line| line.map(|line| line.trim().to_string()))
+}
+
+/// `255.255.255.0`, `24` or `/24`.
+fn mask_or_length(input: &str) -> Option<u32> {
+    let input = input.trim_start_matches('/');
+    match input.parse::<u32>() {
+        Ok(prefix) => (prefix <= 32).then_some(prefix),
+        Err(_) => cidr::mask_prefix(input.parse().ok()?),
+    }
+}
+
+/// The results pane's fields, leaving out any without a value rather than
+/// reading out a dash.
+fn results(ws: &Workspace, geoip: &GeoIp) -> Vec<(String, String)> {
+    let (Some(ip), Some(prefix)) = (ws.ip_address, ws.prefix_length) else {
+        return Vec::new();
+    };
+    let info = SubnetInfo::new(
+        ip,
+        prefix,
+        ws.dhcp.as_ref(),
+        ws.subnetting.as_ref(),
+        &ws.labels,
+    );
+    let mut fields = vec![
+        (tr!("field-cidr"), format!("{}/{}", info.network, prefix)),
+        (tr!("result-network"), info.network.to_string()),
+        (tr!("result-broadcast"), info.broadcast.to_string()),
+        (
+            tr!("result-host-range"),
+            tr!("inline-range", first = info.first, last = info.last),
+        ),
+        (
+            tr!("result-subnet-count"),
+            cidr::group_digits(ws.subnet_count.unwrap_or(0)),
+        ),
+        (
+            tr!("result-host-count"),
+            cidr::group_digits(ws.host_count.unwrap_or(0)),
+        ),
+    ];
+    if let Some(class) = ws.ip_class {
+        fields.push((tr!("result-class"), class.to_string()));
+    }
+    match ws.scope {
+        Some((scope, Some(rfc))) => {
+            fields.push((tr!("result-scope"), format!("{} ({})", scope, rfc)))
+        }
+        Some((scope, None)) => fields.push((tr!("result-scope"), scope.to_string())),
+        None => {}
+    }
+    if !ws.reverse_zones.is_empty() {
+        fields.push((tr!("result-reverse-zone"), ws.reverse_zones.join(", ")));
+    }
+    if let Some(prefix) = ws.hosts_prefix {
+        fields.push((
+            tr!("result-hosts-prefix"),
+            tr!(
+                "inline-sizing",
+                prefix = prefix,
+                mask = Ipv4Addr::from(cidr::prefix_mask(prefix)),
+                usable = cidr::group_digits(cidr::usable_hosts(prefix))
+            ),
+        ));
+    }
+    if let Some(plan) = &ws.subnetting {
+        fields.push((
+            tr!("result-subnetting"),
+            tr!(
+                "calc-subnetting",
+                bits = plan.borrowed_bits,
+                prefix = plan.new_prefix,
+                mask = Ipv4Addr::from(cidr::prefix_mask(plan.new_prefix)),
+                subnets = cidr::group_digits(plan.subnets),
+                hosts = cidr::group_digits(plan.hosts_per_subnet)
+            ),
+        ));
+    }
+    if let Some(scope) = &ws.dhcp {
+        let excluded = match scope.excluded {
+            Some((first, last)) => tr!("calc-dhcp-static", first = first, last = last),
+            None => String::new(),
+        };
+        fields.push((
+            tr!("result-dhcp"),
+            tr!(
+                "inline-dhcp",
+                gateway = scope.gateway,
+                excluded = excluded,
+                first = scope.pool.0,
+                last = scope.pool.1,
+                percent = scope.static_percent
+            ),
+        ));
+    }
+    if let Some(record) = geoip.lookup(ip) {
+        fields.push((tr!("result-location"), record.to_string()));
+    }
+    fields
+}
@@ -0,0 +1,182 @@
+use std::{fmt, ops::Deref};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+}
+
+/// A text field with its own undo/redo history.
+///
+/// Consecutive keystrokes of the same kind are grouped into one undo step,
+/// with a new step starting at each separator so that undo walks back an
+/// address one octet or group at a time. Pastes and programmatic changes are
+/// always a step of their own.
+#[derive(Default)]
+pub struct Input {
+    text: String,
+    undo: Vec<String>,
+    redo: Vec<String>,
+    last_edit: Option<EditKind>,
+}
+
+const UNDO_LIMIT: usize = 100;
+
+impl Input {
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    fn checkpoint(&mut self, kind: Option<EditKind>) {
+        if kind.is_none() || kind != self.last_edit {
+            self.undo.push(self.text.clone());
+            if self.undo.len() > UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_edit = kind;
+    }
+
+    pub fn push(&mut self, c: char) {
+        if matches!(c, '.' | ':' | '/' | '-' | ',' | ' ' | '\n') {
+            self.last_edit = None;
+        }
+        self.checkpoint(Some(EditKind::Insert));
+        self.text.push(c);
+    }
+
+    pub fn pop(&mut self) {
+        if self.text.is_empty() {
+            return;
+        }
+        self.checkpoint(Some(EditKind::Delete));
+        self.text.pop();
+    }
+
+    pub fn insert_str(&mut self, s: &str) {
+        self.checkpoint(None);
+        self.text.push_str(s);
+    }
+
+    /// Replaces the whole text as a single undoable step.
+    pub fn set(&mut self, s: impl Into<String>) {
+        let s = s.into();
+        if s != self.text {
+            self.checkpoint(None);
+            self.text = s;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.set(String::new());
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.undo.pop() else {
+            return false;
+        };
+        self.redo.push(std::mem::replace(&mut self.text, previous));
+        self.last_edit = None;
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        self.undo.push(std::mem::replace(&mut self.text, next));
+        self.last_edit = None;
+        true
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            ..Self::default()
+        }
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(text: &str) -> Input {
+        let mut input = Input::default();
+        text.chars().for_each(|c| input.push(c));
+        input
+    }
+
+    #[test]
+    fn undo_walks_back_one_octet_at_a_time() {
+        let mut input = typed("10.1.2");
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "10.1");
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "10");
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "");
+        assert!(!input.undo());
+    }
+
+    #[test]
+    fn redo_replays_undone_steps() {
+        let mut input = typed("10.1");
+        input.pop();
+        input.pop();
+        input.insert_str("/8");
+        assert_eq!(input.as_str(), "10/8");
+
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "10");
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "10.1");
+        assert!(input.redo());
+        assert_eq!(input.as_str(), "10");
+        assert!(input.redo());
+        assert_eq!(input.as_str(), "10/8");
+        assert!(!input.redo());
+    }
+
+    #[test]
+    fn a_new_edit_clears_redo() {
+        let mut input = typed("10.1");
+        assert!(input.undo());
+        input.set("172.16.0.0");
+        assert!(!input.redo());
+        assert_eq!(input.as_str(), "172.16.0.0");
+        assert!(input.undo());
+        assert_eq!(input.as_str(), "10");
+    }
+
+    #[test]
+    fn undo_and_redo_on_an_empty_history_do_nothing() {
+        let mut input = Input::from("10.0.0.0".to_string());
+        assert!(!input.undo());
+        assert!(!input.redo());
+        assert_eq!(input.as_str(), "10.0.0.0");
+
+        let mut input = typed("10");
+        input.set("10");
+        assert!(input.undo());
+        assert!(!input.undo());
+        assert_eq!(input.as_str(), "");
+    }
+}
@@ -0,0 +1,54 @@
+use std::net::Ipv4Addr;
+
+use if_addrs::IfAddr;
+
+use crate::i18n::tr;
+
+/// An IPv4 address configured on a local network interface.
+pub struct LocalAddress {
+    pub name: String,
+    pub ip: Ipv4Addr,
+    pub netmask: Ipv4Addr,
+    pub prefix: u8,
+}
+
+/// A popup listing the machine's IPv4 addresses to prefill from.
+pub struct InterfacePicker {
+    pub addresses: Vec<LocalAddress>,
+    pub selected: usize,
+    pub error: Option<String>,
+}
+
+impl InterfacePicker {
+    /// Enumerates the interfaces now, so the list reflects the current
+    /// configuration each time the picker opens.
+    pub fn new() -> Self {
+        let (addresses, error) = match if_addrs::get_if_addrs() {
+            Ok(interfaces) => {
+                let addresses = interfaces
+                    .into_iter()
+                    .filter_map(|interface| match interface.addr {
+                        IfAddr::V4(addr) => Some(LocalAddress {
+                            name: interface.name,
+                            ip: addr.ip,
+                            netmask: addr.netmask,
+                            prefix: addr.prefixlen,
+                        }),
+                        IfAddr::V6(_) => None,
+                    })
+                    .collect();
+                (addresses, None)
+            }
+            Err(err) => (Vec::new(), Some(tr!("interfaces-failed", error = err))),
+        };
+        Self {
+            addresses,
+            selected: 0,
+            error,
+        }
+    }
+
+    pub fn chosen(&self) -> Option<&LocalAddress> {
+        self.addresses.get(self.selected)
+    }
+}
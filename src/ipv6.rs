@@ -0,0 +1,352 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{i18n::tr, input::Input};
+
+/// An IPv6 address broken down for display.
+pub struct Ipv6Forms {
+    pub address: Ipv6Addr,
+    pub zone: Option<String>,
+    pub prefix: Option<u32>,
+}
+
+impl Ipv6Forms {
+    /// RFC 5952 form: lowercase, leading zeros dropped, the longest run
+    /// of zero groups replaced by `::`.
+    pub fn compressed(&self) -> String {
+        with_zone(self.address.to_string(), &self.zone)
+    }
+
+    /// All eight groups, four digits each.
+    pub fn expanded(&self) -> String {
+        let groups: Vec<String> = self
+            .address
+            .segments()
+            .iter()
+            .map(|g| format!("{:04x}", g))
+            .collect();
+        with_zone(groups.join(":"), &self.zone)
+    }
+
+    /// The block the address sits in, when a prefix length was given.
+    pub fn network(&self) -> Option<String> {
+        let prefix = self.prefix?;
+        let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+        let network = Ipv6Addr::from(u128::from(self.address) & mask);
+        Some(format!("{}/{}", network, prefix))
+    }
+
+    pub fn is_link_local(&self) -> bool {
+        self.address.segments()[0] & 0xffc0 == 0xfe80
+    }
+}
+
+fn with_zone(address: String, zone: &Option<String>) -> String {
+    match zone {
+        Some(zone) => format!("{}%{}", address, zone),
+        None => address,
+    }
+}
+
+/// Parses an address in any form with an optional `%zone` suffix and
+/// `/prefix` length.
+pub fn parse(input: &str) -> Result<Ipv6Forms, String> {
+    let (input, prefix) = match input.trim().split_once('/') {
+        Some((input, prefix)) => match prefix.trim().parse::<u32>() {
+            Ok(prefix) if prefix <= 128 => (input, Some(prefix)),
+            _ => return Err(tr!("ipv6-bad-prefix-length", prefix = prefix.trim())),
+        },
+        None => (input.trim(), None),
+    };
+    let (address, zone) = match input.split_once('%') {
+        Some((address, zone)) => (address, Some(zone)),
+        None => (input, None),
+    };
+    let address = address
+        .parse::<Ipv6Addr>()
+        .map_err(|_| tr!("ipv6-bad-address", address = address))?;
+    if let Some(zone) = zone {
+        validate_zone(zone)?;
+    }
+    Ok(Ipv6Forms {
+        address,
+        zone: zone.map(str::to_string),
+        prefix,
+    })
+}
+
+/// Zone IDs name an interface (`eth0`) or its index (`3`). RFC 6874 allows
+/// only unreserved URI characters so they survive in URLs.
+fn validate_zone(zone: &str) -> Result<(), String> {
+    if zone.is_empty() {
+        return Err(tr!("ipv6-empty-zone"));
+    }
+    match zone
+        .chars()
+        .find(|&c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~')))
+    {
+        Some(c) => Err(tr!("ipv6-bad-zone", character = c)),
+        None => Ok(()),
+    }
+}
+
+/// The well-known NAT64 prefix, `64:ff9b::/96` (RFC 6052).
+const NAT64_PREFIX: u128 = 0x0064_ff9b << 96;
+
+/// The Teredo prefix, `2001::/32` (RFC 4380).
+const TEREDO_PREFIX: u128 = 0x2001_0000 << 96;
+
+/// `ip` as an IPv4-mapped address, its 6to4 /48 and its NAT64 address.
+pub fn transition_forms(ip: Ipv4Addr) -> Vec<(String, String)> {
+    let bits = u128::from(u32::from(ip));
+    let six_to_four = Ipv6Addr::from(0x2002 << 112 | bits << 80);
+    let nat64 = Ipv6Addr::from(NAT64_PREFIX | bits);
+    vec![
+        (
+            format!("{} (::ffff:0:0/96)", tr!("ipv6-kind-mapped")),
+            ip.to_ipv6_mapped().to_string(),
+        ),
+        (
+            format!("{} (2002::/16)", tr!("ipv6-kind-6to4")),
+            format!("{}/48", six_to_four),
+        ),
+        (
+            format!("{} (64:ff9b::/96)", tr!("ipv6-kind-nat64")),
+            format!("{} = 64:ff9b::{}", nat64, ip),
+        ),
+    ]
+}
+
+/// The IPv4 address carried inside a mapped, 6to4, NAT64 or Teredo
+/// address; for Teredo that is the client's, stored inverted in the last
+/// 32 bits (RFC 4380).
+pub fn embedded_ipv4(address: Ipv6Addr) -> Option<(String, Ipv4Addr)> {
+    let bits = u128::from(address);
+    if let Some(ip) = address.to_ipv4_mapped() {
+        Some((tr!("ipv6-kind-mapped"), ip))
+    } else if bits >> 112 == 0x2002 {
+        Some((tr!("ipv6-kind-6to4"), Ipv4Addr::from((bits >> 80) as u32)))
+    } else if bits >> 32 == NAT64_PREFIX >> 32 {
+        Some((tr!("ipv6-kind-nat64"), Ipv4Addr::from(bits as u32)))
+    } else if bits >> 96 == TEREDO_PREFIX >> 96 {
+        Some((tr!("ipv6-kind-teredo"), Ipv4Addr::from(!(bits as u32))))
+    } else {
+        None
+    }
+}
+
+/// Parses a MAC written with `:` or `-` between bytes, Cisco's dotted
+/// `aabb.ccdd.eeff`, or bare hex.
+pub fn parse_mac(input: &str) -> Option<[u8; 6]> {
+    let digits: String = input
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ':' | '-' | '.'))
+        .collect();
+    if digits.len() != 12 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut mac = [0; 6];
+    for (i, byte) in mac.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(mac)
+}
+
+/// Each step of deriving a modified EUI-64 interface identifier (RFC 4291
+/// appendix A), kept so the screen can show the working.
+pub struct Eui64 {
+    pub mac: [u8; 6],
+    /// The MAC split in half with `ff:fe` inserted.
+    pub padded: [u8; 8],
+    /// `padded` with the universal/local bit (0x02 of the first byte)
+    /// flipped.
+    pub flipped: [u8; 8],
+    pub address: Ipv6Addr,
+}
+
+pub fn eui64(mac: [u8; 6], prefix: Ipv6Addr) -> Eui64 {
+    let padded = [mac[0], mac[1], mac[2], 0xff, 0xfe, mac[3], mac[4], mac[5]];
+    let mut flipped = padded;
+    flipped[0] ^= 0x02;
+    let network = u128::from(prefix) & !u128::from(u64::MAX);
+    let address = Ipv6Addr::from(network | u128::from(u64::from_be_bytes(flipped)));
+    Eui64 {
+        mac,
+        padded,
+        flipped,
+        address,
+    }
+}
+
+/// Bytes as colon-separated hex pairs, the way MACs are written.
+pub fn hex_bytes(bytes: &[u8]) -> String {
+    let pairs: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    pairs.join(":")
+}
+
+/// Parses a prefix for EUI-64, which only works on a /64.
+fn parse_prefix(input: &str) -> Result<Ipv6Addr, String> {
+    let (address, length) = match input.trim().split_once('/') {
+        Some((address, length)) => (address, Some(length.trim())),
+        None => (input.trim(), None),
+    };
+    let prefix = address
+        .parse::<Ipv6Addr>()
+        .map_err(|_| tr!("ipv6-bad-prefix", prefix = address))?;
+    match length {
+        None | Some("64") => Ok(prefix),
+        Some(length) => Err(tr!("eui64-needs-64", length = length)),
+    }
+}
+
+pub enum Ipv6Input {
+    Address,
+    Mac,
+    Prefix,
+    NoTyping,
+}
+
+pub struct Ipv6Tool {
+    pub input: Input,
+    pub mac_input: Input,
+    pub prefix_input: Input,
+    pub input_mode: Ipv6Input,
+    pub result: Option<Result<Ipv6Forms, String>>,
+    pub eui64: Option<Result<Eui64, String>>,
+    /// Where a generated address came from.
+    pub note: Option<String>,
+}
+
+impl Ipv6Tool {
+    pub fn new() -> Self {
+        Self {
+            input: Input::default(),
+            mac_input: Input::default(),
+            prefix_input: Input::default(),
+            input_mode: Ipv6Input::NoTyping,
+            result: None,
+            eui64: None,
+            note: None,
+        }
+    }
+
+    pub fn convert(&mut self) {
+        self.note = None;
+        self.result = Some(parse(&self.input));
+    }
+
+    /// Derives the EUI-64 address once both the MAC and prefix are in.
+    pub fn derive(&mut self) {
+        if self.mac_input.trim().is_empty() || self.prefix_input.trim().is_empty() {
+            self.eui64 = None;
+            return;
+        }
+        self.eui64 = Some(match parse_mac(&self.mac_input) {
+            Some(mac) => parse_prefix(&self.prefix_input).map(|prefix| eui64(mac, prefix)),
+            None => Err(tr!("eui64-bad-mac", mac = self.mac_input.trim())),
+        });
+    }
+
+    /// Enter or Esc in any field.
+    pub fn submit(&mut self) {
+        match self.input_mode {
+            Ipv6Input::Address => self.convert(),
+            Ipv6Input::Mac | Ipv6Input::Prefix => self.derive(),
+            Ipv6Input::NoTyping => {}
+        }
+        self.input_mode = Ipv6Input::NoTyping;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(address: &str) -> Ipv6Addr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn transition_forms_embed_the_address() {
+        let forms: Vec<String> = transition_forms(Ipv4Addr::new(192, 0, 2, 33))
+            .into_iter()
+            .map(|(_, form)| form)
+            .collect();
+        assert_eq!(
+            forms,
+            [
+                "::ffff:192.0.2.33",
+                "2002:c000:221::/48",
+                "64:ff9b::c000:221 = 64:ff9b::192.0.2.33",
+            ]
+        );
+    }
+
+    #[test]
+    fn embedded_addresses_come_back_out() {
+        let ip = Ipv4Addr::new(192, 0, 2, 33);
+        let cases = [
+            ("::ffff:192.0.2.33", tr!("ipv6-kind-mapped")),
+            ("2002:c000:221:1::5", tr!("ipv6-kind-6to4")),
+            ("64:ff9b::192.0.2.33", tr!("ipv6-kind-nat64")),
+        ];
+        for (input, kind) in cases {
+            assert_eq!(embedded_ipv4(address(input)), Some((kind, ip)), "{}", input);
+        }
+    }
+
+    #[test]
+    fn teredo_clients_are_stored_inverted() {
+        // RFC 4380 section 4: server 65.54.227.120, client 192.0.2.45.
+        assert_eq!(
+            embedded_ipv4(address("2001:0:4136:e378:8000:63bf:3fff:fdd2")),
+            Some((tr!("ipv6-kind-teredo"), Ipv4Addr::new(192, 0, 2, 45)))
+        );
+    }
+
+    #[test]
+    fn other_addresses_embed_nothing() {
+        for input in ["2001:db8::c000:221", "::1", "64:ff9b:1::c000:221"] {
+            assert_eq!(embedded_ipv4(address(input)), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn macs_parse_in_every_notation() {
+        let mac = [0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde];
+        for input in [
+            "34:56:78:9a:bc:de",
+            "34-56-78-9A-BC-DE",
+            "3456.789a.bcde",
+            " 3456789abcde ",
+        ] {
+            assert_eq!(parse_mac(input), Some(mac), "{}", input);
+        }
+        assert_eq!(parse_mac("34:56:78:9a:bc"), None);
+        assert_eq!(parse_mac("34:56:78:9a:bc:dg"), None);
+    }
+
+    #[test]
+    fn eui64_inserts_fffe_and_flips_the_universal_bit() {
+        // RFC 4291 appendix A: a universal MAC gets the U/L bit set.
+        let eui = eui64(
+            parse_mac("34-56-78-9A-BC-DE").unwrap(),
+            address("2001:db8:1:2::"),
+        );
+        assert_eq!(eui.padded, [0x34, 0x56, 0x78, 0xff, 0xfe, 0x9a, 0xbc, 0xde]);
+        assert_eq!(
+            eui.flipped,
+            [0x36, 0x56, 0x78, 0xff, 0xfe, 0x9a, 0xbc, 0xde]
+        );
+        assert_eq!(eui.address, address("2001:db8:1:2:3656:78ff:fe9a:bcde"));
+    }
+
+    #[test]
+    fn eui64_clears_the_bit_of_a_local_mac_and_keeps_only_the_prefix() {
+        let eui = eui64(
+            parse_mac("02:00:5e:10:00:01").unwrap(),
+            address("fe80::dead:beef"),
+        );
+        assert_eq!(eui.address, address("fe80::5eff:fe10:1"));
+    }
+}
@@ -0,0 +1,504 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    i18n::tr,
+    key::{KeyCode, KeyEvent, KeyModifiers},
+};
+
+/// Where a binding applies. Screen contexts are consulted before `Global`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Context {
+    Global,
+    Calculator,
+    Membership,
+    Overlap,
+    Tree,
+    Range,
+    Hosts,
+    Quiz,
+    Sweep,
+    Ipv6,
+    Arithmetic,
+    Cleanup,
+    Explain,
+}
+
+const CONTEXTS: &[(Context, &str)] = &[
+    (Context::Global, "global"),
+    (Context::Calculator, "calculator"),
+    (Context::Membership, "membership"),
+    (Context::Overlap, "overlap"),
+    (Context::Tree, "tree"),
+    (Context::Range, "range"),
+    (Context::Hosts, "hosts"),
+    (Context::Quiz, "quiz"),
+    (Context::Sweep, "sweep"),
+    (Context::Ipv6, "ipv6"),
+    (Context::Arithmetic, "arithmetic"),
+    (Context::Cleanup, "cleanup"),
+    (Context::Explain, "explain"),
+];
+
+impl Context {
+    pub fn from_name(name: &str) -> Option<Self> {
+        CONTEXTS.iter().find(|(_, n)| *n == name).map(|(c, _)| *c)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    CommandLine,
+    CommandPalette,
+    NewWorkspace,
+    CloseWorkspace,
+    NextWorkspace,
+    PrevWorkspace,
+    SelectWorkspace(usize),
+    SaveSession,
+    LoadSession,
+    Undo,
+    Redo,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Back,
+    Toggle,
+    Submit,
+    FocusIp,
+    FocusMask,
+    FocusHosts,
+    FocusSubnets,
+    Calculate,
+    UseHostsPrefix,
+    NextSubnet,
+    PrevSubnet,
+    GrowPrefix,
+    ShrinkPrefix,
+    OpenMembership,
+    OpenOverlap,
+    OpenTree,
+    OpenRange,
+    OpenHosts,
+    OpenQuiz,
+    OpenIpv6,
+    OpenArithmetic,
+    OpenCleanup,
+    GenerateUla,
+    PickInterface,
+    Export,
+    ExportReport,
+    OpenSweep,
+    SwitchProbe,
+    PortScan,
+    LabelHost,
+    ResolveNames,
+    ShrinkStaticRange,
+    GrowStaticRange,
+    CycleCloud,
+    ToggleCheatSheet,
+    ToggleTransition,
+    ToggleDebugLog,
+    ToggleZoom,
+    ClearField,
+    ResetWorkspace,
+    SwapIpMask,
+    LookupRdap,
+    PinCompare,
+    Explain,
+    FocusCidr,
+    FocusMac,
+    EditList,
+    LoadFile,
+    FocusParent,
+    EditRange,
+    JumpToHost,
+    Search,
+    NextMatch,
+}
+
+/// Every action with the name used by the config file and the `:` command
+/// line. The command palette describes each with the `action-<name>`
+/// message.
+pub const ACTIONS: &[(Action, &str)] = &[
+    (Action::Quit, "quit"),
+    (Action::CommandLine, "command-line"),
+    (Action::CommandPalette, "command-palette"),
+    (Action::NewWorkspace, "new-workspace"),
+    (Action::CloseWorkspace, "close-workspace"),
+    (Action::NextWorkspace, "next-workspace"),
+    (Action::PrevWorkspace, "prev-workspace"),
+    (Action::SaveSession, "save-session"),
+    (Action::LoadSession, "load-session"),
+    (Action::Undo, "undo"),
+    (Action::Redo, "redo"),
+    (Action::Up, "up"),
+    (Action::Down, "down"),
+    (Action::PageUp, "page-up"),
+    (Action::PageDown, "page-down"),
+    (Action::Home, "home"),
+    (Action::End, "end"),
+    (Action::Back, "back"),
+    (Action::Toggle, "toggle"),
+    (Action::Submit, "submit"),
+    (Action::FocusIp, "focus-ip"),
+    (Action::FocusMask, "focus-mask"),
+    (Action::FocusHosts, "focus-hosts"),
+    (Action::FocusSubnets, "focus-subnets"),
+    (Action::Calculate, "calculate"),
+    (Action::UseHostsPrefix, "use-hosts-prefix"),
+    (Action::NextSubnet, "next-subnet"),
+    (Action::PrevSubnet, "prev-subnet"),
+    (Action::GrowPrefix, "grow-prefix"),
+    (Action::ShrinkPrefix, "shrink-prefix"),
+    (Action::OpenMembership, "open-membership"),
+    (Action::OpenOverlap, "open-overlap"),
+    (Action::OpenTree, "open-tree"),
+    (Action::OpenRange, "open-range"),
+    (Action::OpenHosts, "open-hosts"),
+    (Action::OpenQuiz, "open-quiz"),
+    (Action::OpenIpv6, "open-ipv6"),
+    (Action::OpenArithmetic, "open-arithmetic"),
+    (Action::OpenCleanup, "open-cleanup"),
+    (Action::GenerateUla, "generate-ula"),
+    (Action::OpenSweep, "open-sweep"),
+    (Action::SwitchProbe, "switch-probe"),
+    (Action::PortScan, "port-scan"),
+    (Action::LabelHost, "label-host"),
+    (Action::ResolveNames, "resolve-names"),
+    (Action::ShrinkStaticRange, "shrink-static-range"),
+    (Action::GrowStaticRange, "grow-static-range"),
+    (Action::CycleCloud, "cycle-cloud"),
+    (Action::ToggleCheatSheet, "toggle-cheat-sheet"),
+    (Action::ToggleTransition, "toggle-transition"),
+    (Action::ClearField, "clear-field"),
+    (Action::ResetWorkspace, "reset"),
+    (Action::SwapIpMask, "swap"),
+    (Action::LookupRdap, "rdap"),
+    (Action::PinCompare, "pin-compare"),
+    (Action::Explain, "explain"),
+    (Action::ToggleZoom, "toggle-zoom"),
+    (Action::ToggleDebugLog, "toggle-debug-log"),
+    (Action::PickInterface, "pick-interface"),
+    (Action::Export, "export"),
+    (Action::ExportReport, "export-report"),
+    (Action::FocusCidr, "focus-cidr"),
+    (Action::FocusMac, "focus-mac"),
+    (Action::EditList, "edit-list"),
+    (Action::LoadFile, "load-file"),
+    (Action::FocusParent, "focus-parent"),
+    (Action::EditRange, "edit-range"),
+    (Action::JumpToHost, "jump-to-host"),
+    (Action::Search, "search"),
+    (Action::NextMatch, "next-match"),
+];
+
+impl Action {
+    pub fn from_name(name: &str) -> Option<Self> {
+        if let Some(n) = name.strip_prefix("workspace-") {
+            return match n.parse() {
+                Ok(n @ 1..=9) => Some(Action::SelectWorkspace(n)),
+                _ => None,
+            };
+        }
+        ACTIONS.iter().find(|(_, n)| *n == name).map(|(a, _)| *a)
+    }
+
+    /// Actions that switch fields, so they still fire from a letter key
+    /// while typing into an address field.
+    pub fn applies_while_typing(self) -> bool {
+        matches!(
+            self,
+            Action::Quit
+                | Action::FocusIp
+                | Action::FocusMask
+                | Action::FocusHosts
+                | Action::FocusSubnets
+                | Action::FocusCidr
+                | Action::SwapIpMask
+        )
+    }
+}
+
+/// A key with the modifiers that matter for matching. Shift is folded into
+/// the character for letter keys, as terminals report it inconsistently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self {
+            code,
+            modifiers: modifiers
+                & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT),
+        }
+    }
+
+    /// Parses keys such as `q`, `Q`, `ctrl+s`, `shift+tab`, `pagedown` or `f5`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (mods, key) = match s.rsplit_once('+') {
+            Some((mods, "")) => (mods.strip_suffix('+').unwrap_or(mods), "+"),
+            Some((mods, key)) => (mods, key),
+            None => ("", s),
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for m in mods.split('+').filter(|m| !m.is_empty()) {
+            modifiers |= match m.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" => KeyCode::Enter,
+                "esc" => KeyCode::Esc,
+                "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                f => KeyCode::F(f.strip_prefix('f')?.parse().ok()?),
+            },
+        };
+        Some(Self::new(code, modifiers))
+    }
+}
+
+impl From<KeyEvent> for KeyBinding {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) if self.modifiers.contains(KeyModifiers::CONTROL) => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::PageUp => f.write_str("PgUp"),
+            KeyCode::PageDown => f.write_str("PgDn"),
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            code => write!(f, "{}", code),
+        }
+    }
+}
+
+/// Maps keys to actions per context.
+pub struct Keymap {
+    bindings: HashMap<Context, Vec<(KeyBinding, Action)>>,
+}
+
+impl Keymap {
+    /// The bindings the calculator has always shipped with.
+    pub fn default_preset() -> Self {
+        let mut keymap = Self {
+            bindings: HashMap::new(),
+        };
+        let workspaces: Vec<(String, Action)> = (1..=9)
+            .map(|n| (n.to_string(), Action::SelectWorkspace(n)))
+            .collect();
+        for (key, action) in &workspaces {
+            keymap.add(Context::Global, key, *action);
+        }
+        for (context, key, action) in [
+            (Context::Global, "q", Action::Quit),
+            (Context::Global, "ctrl+p", Action::CommandPalette),
+            (Context::Global, "ctrl+t", Action::NewWorkspace),
+            (Context::Global, "ctrl+w", Action::CloseWorkspace),
+            (Context::Global, "tab", Action::NextWorkspace),
+            (Context::Global, "ctrl+tab", Action::NextWorkspace),
+            (Context::Global, "backtab", Action::PrevWorkspace),
+            (Context::Global, "ctrl+s", Action::SaveSession),
+            (Context::Global, "ctrl+o", Action::LoadSession),
+            (Context::Global, "ctrl+z", Action::Undo),
+            (Context::Global, "ctrl+y", Action::Redo),
+            (Context::Global, "up", Action::Up),
+            (Context::Global, "down", Action::Down),
+            (Context::Global, "pageup", Action::PageUp),
+            (Context::Global, "pagedown", Action::PageDown),
+            (Context::Global, "home", Action::Home),
+            (Context::Global, "end", Action::End),
+            (Context::Global, "esc", Action::Back),
+            (Context::Global, "f12", Action::ToggleDebugLog),
+            (Context::Global, "z", Action::ToggleZoom),
+            (Context::Global, "ctrl+u", Action::ClearField),
+            (Context::Global, "ctrl+r", Action::ResetWorkspace),
+            (Context::Calculator, "i", Action::FocusIp),
+            (Context::Calculator, "s", Action::FocusMask),
+            (Context::Calculator, "h", Action::FocusHosts),
+            (Context::Calculator, "c", Action::FocusSubnets),
+            (Context::Calculator, "enter", Action::Calculate),
+            (Context::Calculator, "u", Action::UseHostsPrefix),
+            (Context::Calculator, "m", Action::OpenMembership),
+            (Context::Calculator, "o", Action::OpenOverlap),
+            (Context::Calculator, "t", Action::OpenTree),
+            (Context::Calculator, "r", Action::OpenRange),
+            (Context::Calculator, "e", Action::OpenHosts),
+            (Context::Calculator, "Q", Action::OpenQuiz),
+            (Context::Calculator, "v", Action::OpenIpv6),
+            (Context::Calculator, "a", Action::OpenArithmetic),
+            (Context::Calculator, "d", Action::OpenCleanup),
+            (Context::Calculator, "I", Action::PickInterface),
+            (Context::Calculator, "x", Action::Export),
+            (Context::Calculator, "X", Action::ExportReport),
+            (Context::Calculator, "w", Action::OpenSweep),
+            (Context::Calculator, "[", Action::ShrinkStaticRange),
+            (Context::Calculator, "]", Action::GrowStaticRange),
+            (Context::Calculator, "C", Action::CycleCloud),
+            (Context::Calculator, "M", Action::ToggleCheatSheet),
+            (Context::Calculator, "T", Action::ToggleTransition),
+            (Context::Calculator, "S", Action::SwapIpMask),
+            (Context::Calculator, "W", Action::LookupRdap),
+            (Context::Calculator, "P", Action::PinCompare),
+            (Context::Calculator, "E", Action::Explain),
+            (Context::Calculator, "n", Action::NextSubnet),
+            (Context::Calculator, "p", Action::PrevSubnet),
+            (Context::Calculator, "left", Action::ShrinkPrefix),
+            (Context::Calculator, "right", Action::GrowPrefix),
+            (Context::Membership, "i", Action::FocusIp),
+            (Context::Membership, "c", Action::FocusCidr),
+            (Context::Membership, "enter", Action::Calculate),
+            (Context::Overlap, "e", Action::EditList),
+            (Context::Overlap, "f", Action::LoadFile),
+            (Context::Overlap, "/", Action::Search),
+            (Context::Tree, "p", Action::FocusParent),
+            (Context::Tree, "e", Action::EditList),
+            (Context::Tree, "space", Action::Toggle),
+            (Context::Tree, "enter", Action::Toggle),
+            (Context::Range, "i", Action::EditRange),
+            (Context::Range, "/", Action::Search),
+            (Context::Hosts, "g", Action::JumpToHost),
+            (Context::Hosts, "/", Action::Search),
+            (Context::Hosts, "n", Action::NextMatch),
+            (Context::Hosts, "space", Action::Toggle),
+            (Context::Hosts, "P", Action::PortScan),
+            (Context::Hosts, "L", Action::LabelHost),
+            (Context::Hosts, "R", Action::ResolveNames),
+            (Context::Quiz, "enter", Action::Submit),
+            (Context::Sweep, "m", Action::SwitchProbe),
+            (Context::Sweep, "/", Action::Search),
+            (Context::Sweep, "R", Action::ResolveNames),
+            (Context::Ipv6, "i", Action::FocusIp),
+            (Context::Ipv6, "m", Action::FocusMac),
+            (Context::Ipv6, "p", Action::FocusCidr),
+            (Context::Ipv6, "g", Action::GenerateUla),
+            (Context::Arithmetic, "i", Action::FocusIp),
+            (Context::Cleanup, "e", Action::EditList),
+            (Context::Cleanup, "/", Action::Search),
+            (Context::Explain, "E", Action::Explain),
+        ] {
+            keymap.add(context, key, action);
+        }
+        keymap
+    }
+
+    /// The default bindings plus `hjkl` movement and a `:` command line.
+    /// In the calculator `h`/`l` resize the prefix, so the hosts-needed
+    /// field moves to `H`.
+    pub fn vim_preset() -> Self {
+        let mut keymap = Self::default_preset();
+        for (context, key, action) in [
+            (Context::Global, "k", Action::Up),
+            (Context::Global, "j", Action::Down),
+            (Context::Global, "g", Action::Home),
+            (Context::Global, "G", Action::End),
+            (Context::Global, "ctrl+b", Action::PageUp),
+            (Context::Global, "ctrl+f", Action::PageDown),
+            (Context::Global, ":", Action::CommandLine),
+            (Context::Calculator, "h", Action::ShrinkPrefix),
+            (Context::Calculator, "l", Action::GrowPrefix),
+        ] {
+            keymap.add(context, key, action);
+        }
+        keymap.bind(
+            Context::Calculator,
+            Action::FocusHosts,
+            vec![KeyBinding::parse("H").unwrap()],
+        );
+        keymap
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default_preset()),
+            "vim" => Some(Self::vim_preset()),
+            _ => None,
+        }
+    }
+
+    fn add(&mut self, context: Context, key: &str, action: Action) {
+        let key = KeyBinding::parse(key).expect("built-in key binding");
+        let bindings = self.bindings.entry(context).or_default();
+        bindings.retain(|(k, _)| *k != key);
+        bindings.push((key, action));
+    }
+
+    /// Replaces every key bound to `action` in `context` with `keys`.
+    pub fn bind(&mut self, context: Context, action: Action, keys: Vec<KeyBinding>) {
+        let bindings = self.bindings.entry(context).or_default();
+        bindings.retain(|(k, a)| *a != action && !keys.contains(k));
+        bindings.extend(keys.into_iter().map(|k| (k, action)));
+    }
+
+    pub fn lookup(&self, context: Context, key: KeyEvent) -> Option<Action> {
+        let key = KeyBinding::from(key);
+        [context, Context::Global].iter().find_map(|c| {
+            self.bindings
+                .get(c)?
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, a)| *a)
+        })
+    }
+
+    /// The first key bound to `action` in `context` or globally.
+    pub fn key_for(&self, context: Context, action: Action) -> Option<KeyBinding> {
+        [context, Context::Global].iter().find_map(|c| {
+            self.bindings
+                .get(c)?
+                .iter()
+                .find(|(_, a)| *a == action)
+                .map(|(k, _)| *k)
+        })
+    }
+
+    /// The key for `action` as shown in on-screen hints.
+    pub fn hint(&self, context: Context, action: Action) -> String {
+        self.key_for(context, action)
+            .map_or_else(|| tr!("key-unbound"), |k| k.to_string())
+    }
+}
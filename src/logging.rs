@@ -0,0 +1,98 @@
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use tracing_subscriber::{fmt::MakeWriter, EnvFilter};
+
+use crate::session;
+
+/// Lines kept in memory for the debug pane.
+const RECENT_LINES: usize = 200;
+
+/// Overrides the default filter, e.g. `SUBNET_CALCULATOR_LOG=trace` to
+/// also log every key press.
+const FILTER_VAR: &str = "SUBNET_CALCULATOR_LOG";
+
+static RECENT: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+pub fn log_path() -> PathBuf {
+    session::data_dir().join("debug.log")
+}
+
+/// Sends log events to the log file and the debug pane. The terminal is
+/// in raw mode, so nothing goes to stderr; when the file can't be opened
+/// the pane still works.
+pub fn init() {
+    let path = log_path();
+    let file = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| OpenOptions::new().create(true).append(true).open(&path))
+        .ok()
+        .map(|file| Arc::new(Mutex::new(file)));
+    let filter = EnvFilter::try_from_env(FILTER_VAR)
+        .unwrap_or_else(|_| EnvFilter::new("subnet_calculator_tui=debug"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_ansi(false)
+        .with_target(false)
+        .with_writer(Sink { file })
+        .init();
+    tracing::info!(path = %path.display(), "logging started");
+}
+
+/// The newest `count` log lines, oldest first.
+pub fn recent(count: usize) -> Vec<String> {
+    let lines = RECENT.lock().unwrap_or_else(|err| err.into_inner());
+    lines
+        .iter()
+        .skip(lines.len().saturating_sub(count))
+        .cloned()
+        .collect()
+}
+
+struct Sink {
+    file: Option<Arc<Mutex<File>>>,
+}
+
+impl<'a> MakeWriter<'a> for Sink {
+    type Writer = SinkWriter<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        SinkWriter { sink: self }
+    }
+}
+
+/// Receives one formatted event per write.
+struct SinkWriter<'a> {
+    sink: &'a Sink,
+}
+
+impl Write for SinkWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(file) = &self.sink.file {
+            let mut file = file.lock().unwrap_or_else(|err| err.into_inner());
+            // A full disk shouldn't take the pane down with it.
+            let _ = file.write_all(buf);
+        }
+        let mut recent = RECENT.lock().unwrap_or_else(|err| err.into_inner());
+        for line in String::from_utf8_lossy(buf).lines() {
+            if recent.len() == RECENT_LINES {
+                recent.pop_front();
+            }
+            recent.push_back(line.to_string());
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &self.sink.file {
+            Some(file) => file.lock().unwrap_or_else(|err| err.into_inner()).flush(),
+            None => Ok(()),
+        }
+    }
+}
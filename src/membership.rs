@@ -0,0 +1,65 @@
+use std::net::Ipv4Addr;
+
+use crate::{cidr, i18n::tr, input::Input};
+
+pub enum MembershipInput {
+    Ip,
+    Cidr,
+    NoTyping,
+}
+
+pub enum Membership {
+    Inside {
+        network: Ipv4Addr,
+        prefix: u32,
+        offset: u32,
+    },
+    Outside {
+        network: Ipv4Addr,
+        prefix: u32,
+    },
+}
+
+pub struct MembershipCheck {
+    pub ip_input: Input,
+    pub cidr_input: Input,
+    pub input_mode: MembershipInput,
+    pub result: Option<Result<(Ipv4Addr, Membership), String>>,
+}
+
+impl MembershipCheck {
+    pub fn new() -> Self {
+        Self {
+            ip_input: Input::default(),
+            cidr_input: Input::default(),
+            input_mode: MembershipInput::NoTyping,
+            result: None,
+        }
+    }
+
+    pub fn check(&mut self) {
+        let Ok(ip) = self.ip_input.trim().parse::<Ipv4Addr>() else {
+            self.result = Some(Err(tr!("membership-invalid-ip")));
+            return;
+        };
+        let Some((addr, prefix)) = cidr::parse_cidr(&self.cidr_input) else {
+            self.result = Some(Err(tr!("membership-invalid-cidr")));
+            return;
+        };
+        self.result = Some(Ok((ip, membership(ip, addr, prefix))));
+    }
+}
+
+/// Locates `ip` relative to the block `addr/prefix`.
+pub fn membership(ip: Ipv4Addr, addr: Ipv4Addr, prefix: u32) -> Membership {
+    let network = cidr::network_of(addr, prefix);
+    if cidr::network_of(ip, prefix) == network {
+        Membership::Inside {
+            network,
+            prefix,
+            offset: u32::from(ip) - u32::from(network),
+        }
+    } else {
+        Membership::Outside { network, prefix }
+    }
+}
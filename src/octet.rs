@@ -0,0 +1,194 @@
+/// What a keystroke appended to a dotted-quad field should do.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Append {
+    Accept,
+    /// Accept the digit and close the octet with a dot.
+    AcceptWithDot,
+    Reject,
+}
+
+/// Checks a character typed at the end of a dotted quad: digits only while
+/// the octet stays within 0-255 and has no leading zero, at most four
+/// octets, and no empty ones.
+pub fn append(text: &str, c: char) -> Append {
+    let octets = text.split('.').count();
+    let last = text.rsplit('.').next().unwrap_or_default();
+    match c {
+        '.' if last.is_empty() || octets >= 4 => Append::Reject,
+        '.' => Append::Accept,
+        '0'..='9' => {
+            let octet = format!("{}{}", last, c);
+            if last == "0" || octet.parse::<u32>().map_or(true, |v| v > 255) {
+                Append::Reject
+            } else if octet.len() == 3 && octets < 4 {
+                Append::AcceptWithDot
+            } else {
+                Append::Accept
+            }
+        }
+        _ => Append::Reject,
+    }
+}
+
+/// Like [`append`], but a mask may also be started as a `/` prefix length
+/// for the completions to expand: `/` on an empty field, then a number up
+/// to 32.
+pub fn append_mask(text: &str, c: char) -> Append {
+    let Some(length) = text.strip_prefix('/') else {
+        return match c {
+            '/' if text.is_empty() => Append::Accept,
+            _ => append(text, c),
+        };
+    };
+    let typed = format!("{}{}", length, c);
+    if !c.is_ascii_digit() || length == "0" || typed.parse::<u32>().map_or(true, |v| v > 32) {
+        Append::Reject
+    } else {
+        Append::Accept
+    }
+}
+
+/// A selected octet being overwritten in place.
+#[derive(Clone, Copy)]
+pub struct OctetCursor {
+    pub index: usize,
+    /// Set until the first digit replaces the octet's old value.
+    fresh: bool,
+}
+
+impl OctetCursor {
+    pub fn new(index: usize) -> Self {
+        Self { index, fresh: true }
+    }
+
+    /// Moves one octet left or right. Moving right past the last octet
+    /// returns `None`, going back to typing at the end.
+    pub fn step(cursor: Option<Self>, text: &str, forward: bool) -> Option<Self> {
+        let last = text.split('.').count() - 1;
+        match (cursor, forward) {
+            (None, false) => Some(Self::new(last)),
+            (None, true) => None,
+            (Some(c), false) => Some(Self::new(c.index.saturating_sub(1))),
+            (Some(c), true) if c.index < last => Some(Self::new(c.index + 1)),
+            (Some(_), true) => None,
+        }
+    }
+
+    /// Types `c` into the selected octet and returns the new text, or
+    /// `None` when the key is rejected. A full octet, or a dot, moves the
+    /// cursor on; past the last octet it clears the cursor.
+    pub fn type_char(cursor: &mut Option<Self>, text: &str, c: char) -> Option<String> {
+        let this = (*cursor)?;
+        let mut octets: Vec<String> = text.split('.').map(String::from).collect();
+        let last = octets.len() - 1;
+        if this.index > last {
+            *cursor = None;
+            return None;
+        }
+        let advance = |cursor: &mut Option<Self>| {
+            *cursor = (this.index < last).then(|| Self::new(this.index + 1));
+        };
+        if c == '.' {
+            advance(cursor);
+            return Some(text.to_string());
+        }
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        let octet = &mut octets[this.index];
+        let value = if this.fresh {
+            c.to_string()
+        } else {
+            format!("{}{}", octet, c)
+        };
+        if (!this.fresh && octet.as_str() == "0") || value.parse::<u32>().ok()? > 255 {
+            return None;
+        }
+        *octet = value;
+        if octet.len() == 3 {
+            advance(cursor);
+        } else {
+            *cursor = Some(Self {
+                index: this.index,
+                fresh: false,
+            });
+        }
+        Some(octets.join("."))
+    }
+
+    /// Deletes the last digit of the selected octet.
+    pub fn backspace(&mut self, text: &str) -> String {
+        let mut octets: Vec<String> = text.split('.').map(String::from).collect();
+        if let Some(octet) = octets.get_mut(self.index) {
+            octet.pop();
+        }
+        self.fresh = false;
+        octets.join(".")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_keeps_octets_in_range() {
+        assert_eq!(append("", '1'), Append::Accept);
+        assert_eq!(append("25", '5'), Append::AcceptWithDot);
+        assert_eq!(append("25", '6'), Append::Reject);
+        assert_eq!(append("192.168.1.25", '5'), Append::Accept);
+        assert_eq!(append("10.0", '1'), Append::Reject);
+        assert_eq!(append("10.", '0'), Append::Accept);
+    }
+
+    #[test]
+    fn append_places_dots() {
+        assert_eq!(append("", '.'), Append::Reject);
+        assert_eq!(append("10.", '.'), Append::Reject);
+        assert_eq!(append("10", '.'), Append::Accept);
+        assert_eq!(append("10.0.0.1", '.'), Append::Reject);
+        assert_eq!(append("10", 'a'), Append::Reject);
+    }
+
+    #[test]
+    fn append_mask_takes_a_prefix_length() {
+        assert_eq!(append_mask("", '/'), Append::Accept);
+        assert_eq!(append_mask("/", '2'), Append::Accept);
+        assert_eq!(append_mask("/2", '4'), Append::Accept);
+        assert_eq!(append_mask("/3", '2'), Append::Accept);
+        assert_eq!(append_mask("/3", '3'), Append::Reject);
+        assert_eq!(append_mask("/0", '1'), Append::Reject);
+        assert_eq!(append_mask("/24", '.'), Append::Reject);
+        assert_eq!(append_mask("255", '/'), Append::Reject);
+        assert_eq!(append_mask("25", '5'), Append::AcceptWithDot);
+    }
+
+    #[test]
+    fn backspace_deletes_from_the_selected_octet() {
+        let mut cursor = OctetCursor::new(1);
+        assert_eq!(cursor.backspace("10.168.1.1"), "10.16.1.1");
+        assert_eq!(cursor.backspace("10.1.1.1"), "10..1.1");
+        assert_eq!(OctetCursor::new(7).backspace("10.1.1.1"), "10.1.1.1");
+    }
+
+    #[test]
+    fn typing_over_an_octet_replaces_it_and_moves_on() {
+        let mut cursor = Some(OctetCursor::new(2));
+        let text = OctetCursor::type_char(&mut cursor, "10.0.99.1", '2').unwrap();
+        assert_eq!(text, "10.0.2.1");
+        let text = OctetCursor::type_char(&mut cursor, &text, '5').unwrap();
+        let text = OctetCursor::type_char(&mut cursor, &text, '4').unwrap();
+        assert_eq!(text, "10.0.254.1");
+        assert_eq!(cursor.map(|c| c.index), Some(3));
+        assert_eq!(OctetCursor::type_char(&mut cursor, &text, 'x'), None);
+    }
+
+    #[test]
+    fn typing_over_an_octet_refuses_out_of_range_values() {
+        let mut cursor = Some(OctetCursor::new(0));
+        let text = OctetCursor::type_char(&mut cursor, "1.0.0.1", '3').unwrap();
+        let text = OctetCursor::type_char(&mut cursor, &text, '0').unwrap();
+        assert_eq!(OctetCursor::type_char(&mut cursor, &text, '0'), None);
+        assert_eq!(text, "30.0.0.1");
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{cidr::Cidr, i18n::tr, input::Input, routes, search::ListFilter};
+
+pub enum OverlapInput {
+    List,
+    Path,
+    NoTyping,
+}
+
+pub enum Relation {
+    Identical,
+    Contains,
+}
+
+pub struct Overlap {
+    pub outer: Cidr,
+    pub inner: Cidr,
+    pub relation: Relation,
+}
+
+pub struct OverlapCheck {
+    pub list_input: Input,
+    pub path_input: Input,
+    pub input_mode: OverlapInput,
+    pub blocks: Vec<Cidr>,
+    pub invalid: Vec<String>,
+    pub overlaps: Vec<Overlap>,
+    pub scroll: usize,
+    pub filter: ListFilter,
+    pub error: Option<String>,
+}
+
+impl OverlapCheck {
+    pub fn new() -> Self {
+        Self {
+            list_input: Input::default(),
+            path_input: Input::default(),
+            input_mode: OverlapInput::NoTyping,
+            blocks: Vec::new(),
+            invalid: Vec::new(),
+            overlaps: Vec::new(),
+            scroll: 0,
+            filter: ListFilter::new(),
+            error: None,
+        }
+    }
+
+    pub fn analyze(&mut self) {
+        let (blocks, invalid) = parse_list(&self.list_input);
+        self.overlaps = find_overlaps(&blocks);
+        self.blocks = blocks;
+        self.invalid = invalid;
+        self.scroll = 0;
+    }
+
+    pub fn load_file(&mut self) {
+        match std::fs::read_to_string(self.path_input.trim()) {
+            Ok(contents) => {
+                // Saved route table output loads as its destinations.
+                let contents = match routes::import(&contents) {
+                    Some(import) => import.to_list(),
+                    None => contents,
+                };
+                self.list_input.set(contents);
+                self.error = None;
+                self.analyze();
+            }
+            Err(err) => {
+                self.error = Some(tr!(
+                    "overlap-read-failed",
+                    path = self.path_input,
+                    error = err
+                ))
+            }
+        }
+    }
+}
+
+/// Splits free-form text into blocks. Entries may be separated by newlines,
+/// commas or whitespace, and `#` starts a comment.
+pub fn parse_list(text: &str) -> (Vec<Cidr>, Vec<String>) {
+    let mut blocks = Vec::new();
+    let mut invalid = Vec::new();
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        for entry in line.split(|c: char| c == ',' || c.is_whitespace()) {
+            if entry.is_empty() {
+                continue;
+            }
+            match Cidr::parse(entry) {
+                Some(block) => blocks.push(block),
+                None => invalid.push(entry.to_string()),
+            }
+        }
+    }
+    (blocks, invalid)
+}
+
+/// Every overlapping pair, ordered by the start of the enclosing block.
+///
+/// CIDR blocks either nest or are disjoint, so after sorting each block only
+/// needs comparing with the following blocks that start before it ends.
+pub fn find_overlaps(blocks: &[Cidr]) -> Vec<Overlap> {
+    let mut sorted = blocks.to_vec();
+    sorted.sort();
+
+    let mut overlaps = Vec::new();
+    for (i, outer) in sorted.iter().enumerate() {
+        for inner in sorted[i + 1..]
+            .iter()
+            .take_while(|b| b.first() <= outer.last())
+        {
+            let relation = if inner == outer {
+                Relation::Identical
+            } else {
+                Relation::Contains
+            };
+            overlaps.push(Overlap {
+                outer: *outer,
+                inner: *inner,
+                relation,
+            });
+        }
+    }
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(list: &[&str]) -> Vec<(String, String, bool)> {
+        let blocks: Vec<Cidr> = list.iter().map(|b| Cidr::parse(b).unwrap()).collect();
+        find_overlaps(&blocks)
+            .into_iter()
+            .map(|o| {
+                let identical = matches!(o.relation, Relation::Identical);
+                (o.outer.to_string(), o.inner.to_string(), identical)
+            })
+            .collect()
+    }
+
+    fn pair(outer: &str, inner: &str, identical: bool) -> (String, String, bool) {
+        (outer.to_string(), inner.to_string(), identical)
+    }
+
+    #[test]
+    fn disjoint_blocks_do_not_overlap() {
+        assert!(pairs(&["10.0.0.0/24", "10.0.1.0/24", "192.168.0.0/16"]).is_empty());
+    }
+
+    #[test]
+    fn duplicates_are_identical() {
+        assert_eq!(
+            pairs(&["10.0.0.0/24", "10.0.0.0/24"]),
+            [pair("10.0.0.0/24", "10.0.0.0/24", true)]
+        );
+    }
+
+    #[test]
+    fn nested_blocks_pair_with_every_enclosing_block() {
+        assert_eq!(
+            pairs(&[
+                "10.0.1.128/25",
+                "172.16.0.0/12",
+                "10.0.0.0/8",
+                "10.0.1.0/24"
+            ]),
+            [
+                pair("10.0.0.0/8", "10.0.1.0/24", false),
+                pair("10.0.0.0/8", "10.0.1.128/25", false),
+                pair("10.0.1.0/24", "10.0.1.128/25", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_list_separates_invalid_entries() {
+        let (blocks, invalid) =
+            parse_list("10.0.0.0/24, 10.0.1.5 # lab\n\nnot-a-block\n10.0.0.0/33");
+        assert_eq!(
+            blocks,
+            [
+                Cidr::parse("10.0.0.0/24").unwrap(),
+                Cidr::parse("10.0.1.5/32").unwrap()
+            ]
+        );
+        assert_eq!(invalid, ["not-a-block", "10.0.0.0/33"]);
+    }
+}
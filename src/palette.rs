@@ -0,0 +1,68 @@
+use crate::{
+    i18n,
+    keymap::{Action, ACTIONS},
+};
+
+/// The command palette: every action, fuzzy-filtered by what was typed.
+pub struct Palette {
+    pub query: String,
+    pub selected: usize,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            selected: 0,
+        }
+    }
+
+    /// Actions matching the query by name or translated description, best
+    /// match first.
+    pub fn matches(&self) -> Vec<(Action, &'static str, String)> {
+        let mut scored: Vec<(i32, (Action, &str, String))> = ACTIONS
+            .iter()
+            .filter_map(|&(action, name)| {
+                let description = i18n::text(&format!("action-{}", name), None);
+                let score =
+                    fuzzy_score(&self.query, name).max(fuzzy_score(&self.query, &description))?;
+                Some((score, (action, name, description)))
+            })
+            .collect();
+        scored.sort_by_key(|(score, _)| -score);
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    pub fn chosen(&self) -> Option<Action> {
+        self.matches()
+            .get(self.selected)
+            .map(|(action, _, _)| *action)
+    }
+}
+
+/// Scores `text` as a case-insensitive subsequence match of `query`,
+/// rewarding consecutive characters and word starts. `None` if some query
+/// character cannot be found in order.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last = None;
+    for q in query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+    {
+        let i = pos + text[pos..].iter().position(|&c| c == q)?;
+        score += 1;
+        if last.is_some_and(|last| last + 1 == i) {
+            score += 5;
+        }
+        if i == 0 || !text[i - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last = Some(i);
+        pos = i + 1;
+    }
+    Some(score)
+}
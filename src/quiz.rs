@@ -0,0 +1,197 @@
+use std::{
+    net::Ipv4Addr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{cidr, i18n::tr, input::Input};
+
+/// Xorshift64* generator; good enough for picking practice questions.
+struct Rng(u64);
+
+impl Rng {
+    fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Self(nanos | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum QuestionKind {
+    Network,
+    Broadcast,
+    FirstHost,
+    LastHost,
+    UsableHosts,
+    Mask,
+}
+
+const KINDS: [QuestionKind; 6] = [
+    QuestionKind::Network,
+    QuestionKind::Broadcast,
+    QuestionKind::FirstHost,
+    QuestionKind::LastHost,
+    QuestionKind::UsableHosts,
+    QuestionKind::Mask,
+];
+
+pub struct Question {
+    pub ip: Ipv4Addr,
+    pub prefix: u32,
+    pub kind: QuestionKind,
+}
+
+impl Question {
+    pub fn text(&self) -> String {
+        let target = format!("{}/{}", self.ip, self.prefix);
+        match self.kind {
+            QuestionKind::Network => tr!("quiz-network", block = target),
+            QuestionKind::Broadcast => tr!("quiz-broadcast", block = target),
+            QuestionKind::FirstHost => tr!("quiz-first-host", block = target),
+            QuestionKind::LastHost => tr!("quiz-last-host", block = target),
+            QuestionKind::UsableHosts => tr!("quiz-usable-hosts", block = target),
+            QuestionKind::Mask => tr!("quiz-mask", prefix = self.prefix),
+        }
+    }
+
+    pub fn answer(&self) -> String {
+        let network = u32::from(cidr::network_of(self.ip, self.prefix));
+        let broadcast = network | !cidr::prefix_mask(self.prefix);
+        match self.kind {
+            QuestionKind::Network => Ipv4Addr::from(network).to_string(),
+            QuestionKind::Broadcast => Ipv4Addr::from(broadcast).to_string(),
+            QuestionKind::FirstHost => Ipv4Addr::from(network + 1).to_string(),
+            QuestionKind::LastHost => Ipv4Addr::from(broadcast - 1).to_string(),
+            QuestionKind::UsableHosts => cidr::usable_hosts(self.prefix).to_string(),
+            QuestionKind::Mask => Ipv4Addr::from(cidr::prefix_mask(self.prefix)).to_string(),
+        }
+    }
+
+    pub fn check(&self, answer: &str) -> bool {
+        let expected = self.answer();
+        match self.kind {
+            QuestionKind::UsableHosts => answer.trim().replace(',', "") == expected,
+            _ => parse_dotted(answer) == expected.parse().ok(),
+        }
+    }
+}
+
+/// A dotted answer, reading octets like `000` or `010` as decimal.
+fn parse_dotted(answer: &str) -> Option<Ipv4Addr> {
+    let octets: Vec<u8> = answer
+        .trim()
+        .split('.')
+        .map(|octet| octet.parse().ok())
+        .collect::<Option<_>>()?;
+    let octets: [u8; 4] = octets.try_into().ok()?;
+    Some(Ipv4Addr::from(octets))
+}
+
+pub struct Quiz {
+    rng: Rng,
+    pub question: Question,
+    pub answer_input: Input,
+    pub graded: Option<bool>,
+    pub correct: u32,
+    pub asked: u32,
+    pub streak: u32,
+    pub best_streak: u32,
+}
+
+impl Quiz {
+    pub fn new() -> Self {
+        let mut rng = Rng::from_time();
+        let question = random_question(&mut rng);
+        Self {
+            rng,
+            question,
+            answer_input: Input::default(),
+            graded: None,
+            correct: 0,
+            asked: 0,
+            streak: 0,
+            best_streak: 0,
+        }
+    }
+
+    pub fn submit(&mut self) {
+        if self.graded.is_some() {
+            self.question = random_question(&mut self.rng);
+            self.answer_input = Input::default();
+            self.graded = None;
+            return;
+        }
+        let right = self.question.check(&self.answer_input);
+        self.asked += 1;
+        if right {
+            self.correct += 1;
+            self.streak += 1;
+            self.best_streak = self.best_streak.max(self.streak);
+        } else {
+            self.streak = 0;
+        }
+        self.graded = Some(right);
+    }
+}
+
+// Prefixes stay within /8../30 so every question has usable hosts.
+fn random_question(rng: &mut Rng) -> Question {
+    Question {
+        ip: Ipv4Addr::from(rng.next() as u32),
+        prefix: 8 + rng.below(23) as u32,
+        kind: KINDS[rng.below(KINDS.len() as u64) as usize],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(kind: QuestionKind) -> Question {
+        Question {
+            ip: Ipv4Addr::new(172, 16, 5, 77),
+            prefix: 22,
+            kind,
+        }
+    }
+
+    #[test]
+    fn host_counts_may_be_comma_grouped() {
+        let q = question(QuestionKind::UsableHosts);
+        assert!(q.check("1022"));
+        assert!(q.check("1,022"));
+        assert!(q.check(" 1,022\t"));
+        assert!(!q.check("1,024"));
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        assert!(question(QuestionKind::Network).check("  172.16.4.0 "));
+        assert!(question(QuestionKind::Broadcast).check("\t172.16.7.255\n"));
+        assert!(!question(QuestionKind::Network).check(" 172.16.5.0 "));
+    }
+
+    #[test]
+    fn dotted_answers_compare_by_value() {
+        let q = question(QuestionKind::Mask);
+        assert!(q.check("255.255.252.0"));
+        assert!(q.check("255.255.252.000"));
+        assert!(question(QuestionKind::FirstHost).check("172.016.004.001"));
+        assert!(!q.check("255.255.252"));
+        assert!(!q.check("255.255.252.0.0"));
+        assert!(!q.check("255.255.252.256"));
+        assert!(!q.check("22"));
+    }
+}
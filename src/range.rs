@@ -0,0 +1,194 @@
+use std::net::Ipv4Addr;
+
+use crate::{cidr::Cidr, i18n::tr, input::Input, search::ListFilter};
+
+pub struct RangeConverter {
+    pub input: Input,
+    pub typing: bool,
+    pub blocks: Vec<Cidr>,
+    pub scroll: usize,
+    pub filter: ListFilter,
+    pub error: Option<String>,
+}
+
+impl RangeConverter {
+    pub fn new() -> Self {
+        Self {
+            input: Input::default(),
+            typing: false,
+            blocks: Vec::new(),
+            scroll: 0,
+            filter: ListFilter::new(),
+            error: None,
+        }
+    }
+
+    pub fn convert(&mut self) {
+        self.scroll = 0;
+        match parse_range(&self.input) {
+            Some((start, end)) => {
+                self.blocks = range_to_cidrs(start, end);
+                self.error = None;
+            }
+            None => {
+                self.blocks.clear();
+                self.error = Some(tr!("range-invalid"));
+            }
+        }
+    }
+}
+
+/// Parses `start - end` (or `start end`), swapping the ends if reversed.
+pub fn parse_range(input: &str) -> Option<(Ipv4Addr, Ipv4Addr)> {
+    let mut parts = input
+        .split(|c: char| c == '-' || c.is_whitespace())
+        .filter(|p| !p.is_empty());
+    let start: Ipv4Addr = parts.next()?.parse().ok()?;
+    let end: Ipv4Addr = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((start.min(end), start.max(end)))
+}
+
+/// The minimal list of CIDR blocks exactly covering `start..=end`.
+///
+/// Each step takes the largest block that is both aligned at the current
+/// address and does not run past the end of the range.
+pub fn range_to_cidrs(start: Ipv4Addr, end: Ipv4Addr) -> Vec<Cidr> {
+    let mut current = u64::from(u32::from(start));
+    let end = u64::from(u32::from(end));
+    let mut blocks = Vec::new();
+    while current <= end {
+        let align = current.trailing_zeros().min(32);
+        let fit = 63 - (end - current + 1).leading_zeros();
+        let bits = align.min(fit);
+        blocks.push(Cidr::new(Ipv4Addr::from(current as u32), 32 - bits));
+        current += 1 << bits;
+    }
+    blocks
+}
+
+/// The fewest blocks covering exactly the addresses of `blocks`: overlaps
+/// and duplicates collapse, and adjacent blocks join wherever alignment
+/// allows.
+pub fn merge(blocks: &[Cidr]) -> Vec<Cidr> {
+    let mut sorted = blocks.to_vec();
+    sorted.sort();
+    let mut spans: Vec<(u32, u32)> = Vec::new();
+    for block in sorted {
+        match spans.last_mut() {
+            Some((_, end)) if u64::from(block.first()) <= u64::from(*end) + 1 => {
+                *end = (*end).max(block.last())
+            }
+            _ => spans.push((block.first(), block.last())),
+        }
+    }
+    spans
+        .into_iter()
+        .flat_map(|(start, end)| range_to_cidrs(Ipv4Addr::from(start), Ipv4Addr::from(end)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cidrs(start: &str, end: &str) -> Vec<String> {
+        range_to_cidrs(start.parse().unwrap(), end.parse().unwrap())
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn blocks(list: &[&str]) -> Vec<Cidr> {
+        list.iter().map(|b| Cidr::parse(b).unwrap()).collect()
+    }
+
+    #[test]
+    fn aligned_range_is_one_block() {
+        assert_eq!(cidrs("192.168.0.0", "192.168.0.255"), ["192.168.0.0/24"]);
+        assert_eq!(cidrs("10.0.0.7", "10.0.0.7"), ["10.0.0.7/32"]);
+    }
+
+    #[test]
+    fn unaligned_range_takes_largest_fitting_blocks() {
+        assert_eq!(
+            cidrs("10.0.0.1", "10.0.0.6"),
+            ["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/31", "10.0.0.6/32"]
+        );
+        assert_eq!(
+            cidrs("10.0.0.17", "10.0.3.200"),
+            [
+                "10.0.0.17/32",
+                "10.0.0.18/31",
+                "10.0.0.20/30",
+                "10.0.0.24/29",
+                "10.0.0.32/27",
+                "10.0.0.64/26",
+                "10.0.0.128/25",
+                "10.0.1.0/24",
+                "10.0.2.0/24",
+                "10.0.3.0/25",
+                "10.0.3.128/26",
+                "10.0.3.192/29",
+                "10.0.3.200/32",
+            ]
+        );
+    }
+
+    #[test]
+    fn range_reaching_the_top_of_the_address_space() {
+        assert_eq!(cidrs("0.0.0.0", "255.255.255.255"), ["0.0.0.0/0"]);
+        assert_eq!(
+            cidrs("255.255.255.254", "255.255.255.255"),
+            ["255.255.255.254/31"]
+        );
+    }
+
+    #[test]
+    fn parse_range_accepts_either_order() {
+        let (a, b) = (Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 9));
+        assert_eq!(parse_range("10.0.0.1 - 10.0.0.9"), Some((a, b)));
+        assert_eq!(parse_range("10.0.0.9 10.0.0.1"), Some((a, b)));
+        assert_eq!(parse_range("10.0.0.1"), None);
+        assert_eq!(parse_range("10.0.0.1 - 10.0.0.9 - 10.0.0.10"), None);
+    }
+
+    #[test]
+    fn merge_collapses_duplicates_and_nested_blocks() {
+        assert_eq!(
+            merge(&blocks(&["10.0.0.0/24", "10.0.0.0/24", "10.0.0.128/25"])),
+            blocks(&["10.0.0.0/24"])
+        );
+    }
+
+    #[test]
+    fn merge_joins_adjacent_blocks_where_aligned() {
+        assert_eq!(
+            merge(&blocks(&["10.0.0.128/25", "10.0.0.0/25"])),
+            blocks(&["10.0.0.0/24"])
+        );
+        // Adjacent, but together not a single aligned block.
+        assert_eq!(
+            merge(&blocks(&["10.0.1.0/24", "10.0.2.0/24"])),
+            blocks(&["10.0.1.0/24", "10.0.2.0/24"])
+        );
+        assert_eq!(
+            merge(&blocks(&["10.0.0.0/24", "10.0.2.0/24"])),
+            blocks(&["10.0.0.0/24", "10.0.2.0/24"])
+        );
+    }
+
+    #[test]
+    fn merge_at_the_end_of_the_address_space() {
+        assert_eq!(
+            merge(&blocks(&[
+                "255.255.255.255",
+                "255.255.255.254/31",
+                "0.0.0.0/1"
+            ])),
+            blocks(&["0.0.0.0/1", "255.255.255.254/31"])
+        );
+    }
+}
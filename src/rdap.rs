@@ -0,0 +1,143 @@
+use std::{
+    net::Ipv4Addr,
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+use serde_json::Value;
+
+use crate::{i18n::tr, range};
+
+/// Redirects to whichever registry holds the address (RFC 7484
+/// bootstrapping), so one URL covers all five RIRs.
+#[cfg(feature = "rdap")]
+const RDAP_URL: &str = "https://rdap.org/ip/";
+
+/// Who holds a block, from an RDAP IP network object (RFC 9083).
+pub struct RdapRecord {
+    pub handle: Option<String>,
+    pub name: Option<String>,
+    pub org: Option<String>,
+    pub country: Option<String>,
+    /// Origin ASNs. Only ARIN publishes these, as an RDAP extension.
+    pub asns: Vec<u64>,
+    /// The allocated block, several CIDRs when it isn't aligned.
+    pub prefixes: Vec<String>,
+}
+
+/// A lookup running on a background thread.
+pub struct RdapLookup {
+    pub ip: Ipv4Addr,
+    pub result: Option<Result<RdapRecord, String>>,
+    receiver: Receiver<Result<RdapRecord, String>>,
+}
+
+impl RdapLookup {
+    pub fn start(ip: Ipv4Addr) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            tracing::info!(%ip, "RDAP lookup started");
+            let result = fetch(ip).and_then(|body| parse(&body));
+            if let Err(err) = &result {
+                tracing::warn!(%ip, "RDAP lookup failed: {}", err);
+            }
+            let _ = sender.send(result);
+        });
+        Self {
+            ip,
+            result: None,
+            receiver,
+        }
+    }
+
+    pub fn poll(&mut self) {
+        if let Ok(result) = self.receiver.try_recv() {
+            self.result = Some(result);
+        }
+    }
+}
+
+#[cfg(feature = "rdap")]
+fn fetch(ip: Ipv4Addr) -> Result<String, String> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(std::time::Duration::from_secs(15)))
+        .build()
+        .into();
+    agent
+        .get(format!("{}{}", RDAP_URL, ip))
+        .header("Accept", "application/rdap+json")
+        .call()
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[cfg(not(feature = "rdap"))]
+fn fetch(_ip: Ipv4Addr) -> Result<String, String> {
+    Err(tr!("rdap-unsupported"))
+}
+
+fn parse(body: &str) -> Result<RdapRecord, String> {
+    let json: Value =
+        serde_json::from_str(body).map_err(|err| tr!("rdap-unreadable", error = err))?;
+    if json.get("objectClassName").and_then(Value::as_str) != Some("ip network") {
+        let title = json.get("title").and_then(Value::as_str);
+        return Err(title.map_or_else(|| tr!("rdap-no-network"), str::to_string));
+    }
+    let text = |key: &str| json.get(key).and_then(Value::as_str).map(str::to_string);
+    Ok(RdapRecord {
+        handle: text("handle"),
+        name: text("name"),
+        org: registrant(&json),
+        country: text("country"),
+        asns: json
+            .get("arin_originas0_originautnums")
+            .and_then(Value::as_array)
+            .map(|asns| asns.iter().filter_map(Value::as_u64).collect())
+            .unwrap_or_default(),
+        prefixes: prefixes(&json),
+    })
+}
+
+/// The registrant's `fn` (formatted name) from its jCard (RFC 7095).
+fn registrant(json: &Value) -> Option<String> {
+    json.get("entities")?.as_array()?.iter().find_map(|entity| {
+        let roles = entity.get("roles")?.as_array()?;
+        if !roles.iter().any(|role| role == "registrant") {
+            return None;
+        }
+        entity
+            .get("vcardArray")?
+            .get(1)?
+            .as_array()?
+            .iter()
+            .find_map(|property| match property.as_array()?.as_slice() {
+                [name, _, _, value] if name == "fn" => value.as_str().map(str::to_string),
+                _ => None,
+            })
+    })
+}
+
+/// The `cidr0` extension's prefixes, or the start and end addresses
+/// broken into CIDRs where a registry doesn't send them.
+fn prefixes(json: &Value) -> Vec<String> {
+    if let Some(cidrs) = json.get("cidr0_cidrs").and_then(Value::as_array) {
+        let prefixes: Vec<String> = cidrs
+            .iter()
+            .filter_map(|cidr| {
+                let prefix = cidr.get("v4prefix")?.as_str()?;
+                Some(format!("{}/{}", prefix, cidr.get("length")?.as_u64()?))
+            })
+            .collect();
+        if !prefixes.is_empty() {
+            return prefixes;
+        }
+    }
+    let address = |key: &str| json.get(key)?.as_str()?.parse::<Ipv4Addr>().ok();
+    match address("startAddress").zip(address("endAddress")) {
+        Some((start, end)) => range::range_to_cidrs(start, end)
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        None => Vec::new(),
+    }
+}
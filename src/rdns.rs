@@ -0,0 +1,424 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{cidr::prefix_mask, i18n::tr};
+
+/// How long a nameserver gets to answer before the address counts as
+/// having no name.
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Queries in flight at once.
+const WORKERS: usize = 8;
+
+/// DNS record type and class of a PTR query (RFC 1035).
+const TYPE_PTR: u16 = 12;
+const CLASS_IN: u16 = 1;
+
+/// `in-addr.arpa` zones covering `network/prefix`.
+///
+/// Prefixes that are not octet-aligned are split into the zones of the next
+/// longer aligned prefix (a /22 needs four /24 zones). Prefixes from /25 to
+/// /31 get the RFC 2317 classless delegation name inside the enclosing zone,
+/// and a /32 is the address's own PTR name.
+pub fn reverse_zones(network: Ipv4Addr, prefix: u32) -> Vec<String> {
+    let base = u32::from(network) & prefix_mask(prefix);
+    if (25..32).contains(&prefix) {
+        let o = Ipv4Addr::from(base).octets();
+        return vec![format!(
+            "{}/{}.{}.{}.{}.in-addr.arpa",
+            o[3], prefix, o[2], o[1], o[0]
+        )];
+    }
+
+    let zone_prefix = prefix.div_ceil(8) * 8;
+    let count = 1u32 << (zone_prefix - prefix);
+    let step = 1u32.checked_shl(32 - zone_prefix).unwrap_or(0);
+    (0..count)
+        .map(|i| zone_name(Ipv4Addr::from(base + i * step), zone_prefix))
+        .collect()
+}
+
+fn zone_name(network: Ipv4Addr, zone_prefix: u32) -> String {
+    let octets = network.octets();
+    let mut labels: Vec<String> = octets[..(zone_prefix / 8) as usize]
+        .iter()
+        .rev()
+        .map(|o| o.to_string())
+        .collect();
+    labels.push("in-addr.arpa".to_string());
+    labels.join(".")
+}
+
+/// The `in-addr.arpa` name whose PTR record names `ip`.
+pub fn ptr_name(ip: Ipv4Addr) -> String {
+    zone_name(ip, 32)
+}
+
+pub enum Ptr {
+    Pending,
+    Name(String),
+    /// No PTR record, or no answer in time.
+    Missing,
+}
+
+/// PTR lookups run by a pool of worker threads. Addresses are queued as
+/// they are listed and each tick collects the answers that arrived, so
+/// names fill in row by row.
+pub struct Resolver {
+    pub names: HashMap<Ipv4Addr, Ptr>,
+    /// When the sweep whose targets were queued started, so a sweep's
+    /// targets are queued once rather than every tick.
+    pub sweep_queued: Option<Instant>,
+    jobs: Sender<Ipv4Addr>,
+    receiver: Receiver<(Ipv4Addr, Option<String>)>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Resolver {
+    /// Starts the workers, asking `server` or else the system's first
+    /// nameserver.
+    pub fn start(server: Option<IpAddr>) -> Result<Self, String> {
+        let server = server
+            .map(|ip| SocketAddr::new(ip, 53))
+            .or_else(nameserver)
+            .ok_or_else(|| tr!("error-rdns-no-nameserver"))?;
+        tracing::info!(%server, "PTR lookups started");
+        let (jobs, queue) = mpsc::channel::<Ipv4Addr>();
+        let (sender, receiver) = mpsc::channel();
+        let queue = Arc::new(Mutex::new(queue));
+        let cancel = Arc::new(AtomicBool::new(false));
+        for worker in 0..WORKERS {
+            let (queue, stop, sender) = (Arc::clone(&queue), Arc::clone(&cancel), sender.clone());
+            thread::spawn(move || {
+                let socket = match bind(server) {
+                    Ok(socket) => socket,
+                    Err(err) => {
+                        tracing::warn!("PTR lookup socket failed: {}", err);
+                        return;
+                    }
+                };
+                let mut id = worker as u16;
+                loop {
+                    let next = queue.lock().ok().and_then(|queue| queue.recv().ok());
+                    let Some(ip) = next else {
+                        break;
+                    };
+                    if stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    id = id.wrapping_add(WORKERS as u16);
+                    let name = query(&socket, server, ip, id).unwrap_or_else(|err| {
+                        tracing::debug!(%ip, "PTR lookup failed: {}", err);
+                        None
+                    });
+                    if sender.send((ip, name)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        Ok(Self {
+            names: HashMap::new(),
+            sweep_queued: None,
+            jobs,
+            receiver,
+            cancel,
+        })
+    }
+
+    /// Queues the addresses not looked up yet.
+    pub fn request(&mut self, ips: impl IntoIterator<Item = Ipv4Addr>) {
+        for ip in ips {
+            if !self.names.contains_key(&ip) && self.jobs.send(ip).is_ok() {
+                self.names.insert(ip, Ptr::Pending);
+            }
+        }
+    }
+
+    /// Applies every answer received since the last call.
+    pub fn poll(&mut self) {
+        while let Ok((ip, name)) = self.receiver.try_recv() {
+            self.names.insert(ip, name.map_or(Ptr::Missing, Ptr::Name));
+        }
+    }
+
+    /// What to show beside `ip`: its name, `...` while waiting, or
+    /// nothing.
+    pub fn label(&self, ip: Ipv4Addr) -> &str {
+        match self.names.get(&ip) {
+            Some(Ptr::Name(name)) => name,
+            Some(Ptr::Pending) => "...",
+            Some(Ptr::Missing) | None => "",
+        }
+    }
+}
+
+impl Drop for Resolver {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+/// The first nameserver in `/etc/resolv.conf`.
+fn nameserver() -> Option<SocketAddr> {
+    let conf = fs::read_to_string("/etc/resolv.conf").ok()?;
+    conf.lines().find_map(|line| {
+        let mut words = line.split_whitespace();
+        if words.next() != Some("nameserver") {
+            return None;
+        }
+        // Scoped IPv6 addresses like fe80::1%eth0 don't parse and are
+        // passed over.
+        let ip: IpAddr = words.next()?.parse().ok()?;
+        Some(SocketAddr::new(ip, 53))
+    })
+}
+
+fn bind(server: SocketAddr) -> io::Result<UdpSocket> {
+    let socket = if server.is_ipv4() {
+        UdpSocket::bind("0.0.0.0:0")?
+    } else {
+        UdpSocket::bind("[::]:0")?
+    };
+    socket.set_read_timeout(Some(TIMEOUT))?;
+    socket.connect(server)?;
+    Ok(socket)
+}
+
+/// Asks `server` for the PTR record of `ip`. Answers to earlier queries
+/// that timed out are skipped by their id.
+fn query(
+    socket: &UdpSocket,
+    server: SocketAddr,
+    ip: Ipv4Addr,
+    id: u16,
+) -> io::Result<Option<String>> {
+    socket.send(&encode_query(id, &ptr_name(ip)))?;
+    let mut packet = [0; 512];
+    loop {
+        let len = socket.recv(&mut packet)?;
+        if let Some(answer) = parse_response(&packet[..len], id) {
+            tracing::trace!(%ip, %server, ?answer, "PTR answer");
+            return Ok(answer);
+        }
+    }
+}
+
+fn encode_query(id: u16, name: &str) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(12 + name.len() + 6);
+    packet.extend_from_slice(&id.to_be_bytes());
+    // Recursion desired, one question.
+    packet.extend_from_slice(&[0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in name.split('.') {
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+    packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+    packet
+}
+
+/// The PTR name in a response to query `id`: `None` when the packet
+/// answers something else or is malformed, `Some(None)` when there is no
+/// name.
+fn parse_response(packet: &[u8], id: u16) -> Option<Option<String>> {
+    let word = |at: usize| Some(u16::from_be_bytes([*packet.get(at)?, *packet.get(at + 1)?]));
+    let flags = word(2)?;
+    if word(0)? != id || flags & 0x8000 == 0 {
+        return None;
+    }
+    if flags & 0x000f != 0 {
+        return Some(None);
+    }
+    let mut at = 12;
+    for _ in 0..word(4)? {
+        at = read_name(packet, at)?.1 + 4;
+    }
+    for _ in 0..word(6)? {
+        at = read_name(packet, at)?.1;
+        let (kind, len) = (word(at)?, usize::from(word(at + 8)?));
+        let data = at + 10;
+        if kind == TYPE_PTR {
+            return read_name(packet, data).map(|(name, _)| Some(name));
+        }
+        at = data + len;
+    }
+    Some(None)
+}
+
+/// Reads a possibly compressed domain name at `at`, returning it and the
+/// offset just past it.
+fn read_name(packet: &[u8], mut at: usize) -> Option<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    // Bounds the pointers followed, so a looping packet can't hang us.
+    for _ in 0..64 {
+        let len = *packet.get(at)?;
+        match len {
+            0 => {
+                return Some((labels.join("."), end.unwrap_or(at + 1)));
+            }
+            len if len & 0xc0 == 0xc0 => {
+                end.get_or_insert(at + 2);
+                at = usize::from(u16::from_be_bytes([len & 0x3f, *packet.get(at + 1)?]));
+            }
+            len => {
+                let label = packet.get(at + 1..at + 1 + usize::from(len))?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                at += 1 + usize::from(len);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A response to `id` echoing the PTR question for 1.2.0.192, with
+    /// `answers` appended and counted as `count` records.
+    fn response(id: u16, rcode: u8, count: u16, answers: &[u8]) -> Vec<u8> {
+        let mut packet = encode_query(id, "1.2.0.192.in-addr.arpa");
+        packet[2] |= 0x80;
+        packet[3] |= rcode;
+        packet[6..8].copy_from_slice(&count.to_be_bytes());
+        packet.extend_from_slice(answers);
+        packet
+    }
+
+    /// A record owned by the question's name (a pointer to offset 12)
+    /// with `data` as its rdata.
+    fn record(kind: u16, data: &[u8]) -> Vec<u8> {
+        let mut record = vec![0xc0, 12];
+        record.extend_from_slice(&kind.to_be_bytes());
+        record.extend_from_slice(&CLASS_IN.to_be_bytes());
+        record.extend_from_slice(&300u32.to_be_bytes());
+        record.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        record.extend_from_slice(data);
+        record
+    }
+
+    #[test]
+    fn reverse_zones_follow_octet_boundaries() {
+        let net = Ipv4Addr::new(10, 1, 2, 0);
+        assert_eq!(reverse_zones(net, 24), ["2.1.10.in-addr.arpa"]);
+        assert_eq!(
+            reverse_zones(Ipv4Addr::new(10, 1, 0, 0), 22),
+            [
+                "0.1.10.in-addr.arpa",
+                "1.1.10.in-addr.arpa",
+                "2.1.10.in-addr.arpa",
+                "3.1.10.in-addr.arpa",
+            ]
+        );
+        assert_eq!(reverse_zones(net, 16), ["1.10.in-addr.arpa"]);
+        assert_eq!(
+            reverse_zones(Ipv4Addr::new(10, 1, 2, 64), 26),
+            ["64/26.2.1.10.in-addr.arpa"]
+        );
+        assert_eq!(
+            reverse_zones(Ipv4Addr::new(10, 1, 2, 3), 32),
+            ["3.2.1.10.in-addr.arpa"]
+        );
+        assert_eq!(reverse_zones(net, 0), ["in-addr.arpa"]);
+    }
+
+    #[test]
+    fn ptr_name_reverses_octets() {
+        assert_eq!(
+            ptr_name(Ipv4Addr::new(192, 0, 2, 1)),
+            "1.2.0.192.in-addr.arpa"
+        );
+    }
+
+    #[test]
+    fn encode_query_writes_header_and_labels() {
+        let packet = encode_query(0x1234, "1.2.0.192.in-addr.arpa");
+        assert_eq!(packet[..12], [0x12, 0x34, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            packet[12..],
+            *b"\x011\x012\x010\x03192\x07in-addr\x04arpa\x00\x00\x0c\x00\x01"
+        );
+    }
+
+    #[test]
+    fn read_name_reads_labels_and_pointers() {
+        let packet = b"\x03foo\x07example\x00\x03bar\xc0\x04";
+        assert_eq!(read_name(packet, 0), Some(("foo.example".to_string(), 13)));
+        // The offset returned is past the pointer, not past its target.
+        assert_eq!(read_name(packet, 13), Some(("bar.example".to_string(), 19)));
+    }
+
+    #[test]
+    fn read_name_rejects_truncated_and_looping_names() {
+        assert_eq!(read_name(b"\x03fo", 0), None);
+        assert_eq!(read_name(b"\x03foo", 0), None);
+        assert_eq!(read_name(b"\xc0", 0), None);
+        assert_eq!(read_name(b"\x03foo\xc0\x10", 0), None);
+        assert_eq!(read_name(b"\xc0\x00", 0), None);
+        assert_eq!(read_name(b"\x01a\xc0\x00", 0), None);
+    }
+
+    #[test]
+    fn parse_response_finds_ptr_name() {
+        let answer = record(TYPE_PTR, b"\x04host\x07example\x00");
+        assert_eq!(
+            parse_response(&response(7, 0, 1, &answer), 7),
+            Some(Some("host.example".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_response_follows_compressed_ptr_data() {
+        // host + a pointer to "in-addr.arpa" in the question.
+        let answer = record(TYPE_PTR, b"\x04host\xc0\x16");
+        assert_eq!(
+            parse_response(&response(7, 0, 1, &answer), 7),
+            Some(Some("host.in-addr.arpa".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_response_skips_other_records() {
+        let mut answers = record(5, b"\x05alias\x00");
+        answers.extend(record(TYPE_PTR, b"\x04host\x00"));
+        assert_eq!(
+            parse_response(&response(7, 0, 2, &answers), 7),
+            Some(Some("host".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_response_without_a_name() {
+        // NXDOMAIN.
+        assert_eq!(parse_response(&response(7, 3, 0, &[]), 7), Some(None));
+        assert_eq!(parse_response(&response(7, 0, 0, &[]), 7), Some(None));
+    }
+
+    #[test]
+    fn parse_response_ignores_other_ids_and_queries() {
+        let answer = record(TYPE_PTR, b"\x04host\x00");
+        assert_eq!(parse_response(&response(8, 0, 1, &answer), 7), None);
+        assert_eq!(parse_response(&encode_query(7, "x.in-addr.arpa"), 7), None);
+    }
+
+    #[test]
+    fn parse_response_rejects_truncated_packets() {
+        let packet = response(7, 0, 1, &record(TYPE_PTR, b"\x04host\x00"));
+        for len in [0, 3, 11, 20, packet.len() - 20, packet.len() - 1] {
+            assert_eq!(parse_response(&packet[..len], 7), None, "length {len}");
+        }
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    fs,
+    io::{self, Write},
+    net::Ipv4Addr,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{app::Workspace, cidr, export::SubnetInfo, geoip::GeoIp, i18n::tr};
+
+/// Width of the label column, fitting the longest English label.
+const LABEL_WIDTH: usize = 26;
+
+/// Writes a plain-text report of the workspace's calculation to the
+/// working directory, named after the block and the time it was written.
+/// A second report in the same second gets a `-2`, `-3`, ... suffix
+/// rather than replacing the first.
+pub fn save(ws: &Workspace, geoip: &GeoIp) -> Result<PathBuf, String> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let (Some(network), Some(prefix), Some(report)) =
+        (ws.network_address, ws.prefix_length, render(ws, geoip, now))
+    else {
+        return Err(tr!("error-report-no-subnet"));
+    };
+    let stem = format!(
+        "subnet-{}-{}-{}",
+        network,
+        prefix,
+        timestamp(now).replace(['-', ':'], "").replace(' ', "-")
+    );
+    write_new(&stem, &report).map_err(|err| tr!("error-report-write", error = err))
+}
+
+/// Writes `contents` to `<stem>.txt`, or the first of `<stem>-2.txt`,
+/// `<stem>-3.txt`, ... that doesn't exist yet.
+fn write_new(stem: &str, contents: &str) -> io::Result<PathBuf> {
+    let mut n = 1;
+    loop {
+        let path = PathBuf::from(match n {
+            1 => format!("{}.txt", stem),
+            _ => format!("{}-{}.txt", stem, n),
+        });
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                return Ok(path);
+            }
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => n += 1,
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// The report text, or `None` before a subnet is calculated.
+fn render(ws: &Workspace, geoip: &GeoIp, now: u64) -> Option<String> {
+    let ip = ws.ip_address?;
+    let mask = ws.subnet_mask?;
+    let prefix = ws.prefix_length?;
+    let info = SubnetInfo::new(
+        ip,
+        prefix,
+        ws.dhcp.as_ref(),
+        ws.subnetting.as_ref(),
+        &ws.labels,
+    );
+    let dash = || "-".to_string();
+
+    let mut out = format!(
+        "{}\n{}\n",
+        tr!("report-title"),
+        tr!("report-generated", time = timestamp(now))
+    );
+
+    section(&mut out, &tr!("report-inputs"));
+    field(&mut out, &tr!("report-ip"), ip.to_string());
+    field(&mut out, &tr!("report-mask"), mask.to_string());
+    field(
+        &mut out,
+        &tr!("field-hosts-needed"),
+        or_dash(ws.hosts_input.as_str()),
+    );
+    field(
+        &mut out,
+        &tr!("field-subnets-needed"),
+        or_dash(ws.subnets_input.as_str()),
+    );
+
+    section(&mut out, &tr!("report-results"));
+    field(
+        &mut out,
+        &tr!("field-cidr"),
+        format!("{}/{}", info.network, prefix),
+    );
+    field(&mut out, &tr!("result-network"), info.network.to_string());
+    field(
+        &mut out,
+        &tr!("result-broadcast"),
+        info.broadcast.to_string(),
+    );
+    field(&mut out, &tr!("report-wildcard"), info.wildcard.to_string());
+    field(
+        &mut out,
+        &tr!("result-host-range"),
+        format!("{} - {}", info.first, info.last),
+    );
+    field(
+        &mut out,
+        &tr!("result-subnet-count"),
+        cidr::group_digits(ws.subnet_count.unwrap_or(0)),
+    );
+    field(
+        &mut out,
+        &tr!("result-host-count"),
+        cidr::group_digits(ws.host_count.unwrap_or(0)),
+    );
+    field(
+        &mut out,
+        &tr!("result-cloud"),
+        ws.cloud.map_or_else(dash, |cloud| {
+            let reserved: Vec<String> = cloud
+                .reserved(info.network, prefix)
+                .iter()
+                .map(|r| format!("{} {}", r.address, r.purpose))
+                .collect();
+            tr!(
+                "report-cloud",
+                cloud = cloud,
+                reserved = reserved.join(", "),
+                usable = cidr::group_digits(cloud.usable_hosts(info.network, prefix))
+            )
+        }),
+    );
+    field(
+        &mut out,
+        &tr!("result-class"),
+        ws.ip_class.map_or_else(dash, |class| class.to_string()),
+    );
+    field(
+        &mut out,
+        &tr!("result-scope"),
+        match ws.scope {
+            Some((scope, Some(rfc))) => format!("{} ({})", scope, rfc),
+            Some((scope, None)) => scope.to_string(),
+            None => dash(),
+        },
+    );
+    // Unlike the results pane there's room for every zone.
+    field(
+        &mut out,
+        &tr!("result-reverse-zone"),
+        if ws.reverse_zones.is_empty() {
+            dash()
+        } else {
+            ws.reverse_zones.join(", ")
+        },
+    );
+    field(
+        &mut out,
+        &tr!("result-hosts-prefix"),
+        ws.hosts_prefix.map_or_else(dash, |prefix| {
+            tr!(
+                "inline-sizing",
+                prefix = prefix,
+                mask = Ipv4Addr::from(cidr::prefix_mask(prefix)),
+                usable = cidr::group_digits(cidr::usable_hosts(prefix))
+            )
+        }),
+    );
+    field(
+        &mut out,
+        &tr!("result-subnetting"),
+        ws.subnetting.as_ref().map_or_else(dash, |plan| {
+            tr!(
+                "calc-subnetting",
+                bits = plan.borrowed_bits,
+                prefix = plan.new_prefix,
+                mask = Ipv4Addr::from(cidr::prefix_mask(plan.new_prefix)),
+                subnets = cidr::group_digits(plan.subnets),
+                hosts = cidr::group_digits(plan.hosts_per_subnet)
+            )
+        }),
+    );
+    field(
+        &mut out,
+        &tr!("result-dhcp"),
+        ws.dhcp.as_ref().map_or_else(dash, |scope| {
+            let excluded = match scope.excluded {
+                Some((first, last)) => tr!("calc-dhcp-static", first = first, last = last),
+                None => String::new(),
+            };
+            tr!(
+                "inline-dhcp",
+                gateway = scope.gateway,
+                excluded = excluded,
+                first = scope.pool.0,
+                last = scope.pool.1,
+                percent = scope.static_percent
+            )
+        }),
+    );
+    if geoip.is_loaded() {
+        field(
+            &mut out,
+            &tr!("result-location"),
+            geoip
+                .lookup(ip)
+                .map_or_else(dash, |record| record.to_string()),
+        );
+    }
+
+    if !ws.warnings.is_empty() {
+        section(&mut out, &tr!("calc-warnings"));
+        for warning in &ws.warnings {
+            out.push_str(&format!("  {}\n", warning));
+        }
+    }
+
+    if !info.labels.is_empty() {
+        section(&mut out, &tr!("report-labels"));
+        for (host, label) in &info.labels {
+            field(&mut out, &host.to_string(), label.clone());
+        }
+    }
+
+    section(&mut out, &tr!("report-binary", prefix = prefix));
+    for (label, addr) in [
+        (tr!("binary-ip"), ip),
+        (tr!("binary-mask"), mask),
+        (tr!("binary-network"), info.network),
+        (tr!("binary-broadcast"), info.broadcast),
+    ] {
+        out.push_str(&format!("  {:<9} {}\n", label, binary(addr, prefix)));
+    }
+
+    section(&mut out, &tr!("help-cheat-sheet"));
+    out.push_str(&format!(
+        "  {:<6} {:<16} {:<16} {:<14} {}\n",
+        tr!("field-prefix"),
+        tr!("column-mask"),
+        tr!("column-wildcard"),
+        tr!("column-hosts"),
+        tr!("column-block")
+    ));
+    out.push_str(&format!(
+        "  {:<6} {:<16} {:<16} {:<14} {}\n",
+        format!("/{}", prefix),
+        info.mask,
+        info.wildcard,
+        cidr::group_digits(cidr::usable_hosts(prefix)),
+        cidr::group_digits(cidr::block_size(prefix))
+    ));
+    Some(out)
+}
+
+fn section(out: &mut String, title: &str) {
+    out.push_str(&format!(
+        "\n{}\n{}\n",
+        title,
+        "=".repeat(title.chars().count())
+    ));
+}
+
+fn field(out: &mut String, label: &str, value: String) {
+    out.push_str(&format!(
+        "  {:<width$} {}\n",
+        format!("{}:", label),
+        value,
+        width = LABEL_WIDTH
+    ));
+}
+
+fn or_dash(text: &str) -> String {
+    if text.is_empty() {
+        "-".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Dotted binary with a `|` between the network and host bits.
+fn binary(addr: Ipv4Addr, prefix: u32) -> String {
+    let bits = u32::from(addr);
+    let mut out = String::new();
+    for i in 0..32 {
+        if i > 0 && i % 8 == 0 {
+            out.push('.');
+        }
+        if i == prefix && prefix > 0 {
+            out.push('|');
+        }
+        out.push(if bits & (1 << (31 - i)) != 0 {
+            '1'
+        } else {
+            '0'
+        });
+    }
+    out
+}
+
+/// `YYYY-MM-DD HH:MM:SS` in UTC, from seconds since the epoch.
+fn timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let time = secs % 86_400;
+    // Howard Hinnant's days-to-civil conversion.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_start_at_the_epoch() {
+        assert_eq!(timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(timestamp(86_399), "1970-01-01 23:59:59");
+    }
+
+    #[test]
+    fn timestamps_across_leap_days() {
+        assert_eq!(timestamp(1_709_210_096), "2024-02-29 12:34:56");
+        assert_eq!(timestamp(951_868_799), "2000-02-29 23:59:59");
+        assert_eq!(timestamp(951_868_800), "2000-03-01 00:00:00");
+        assert_eq!(timestamp(4_107_542_400), "2100-03-01 00:00:00");
+    }
+
+    #[test]
+    fn reports_in_the_same_second_do_not_overwrite() {
+        let dir = std::env::temp_dir().join(format!("subnet-report-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let stem = dir.join("subnet-10.0.0.0-24-19700101-000000");
+        let stem = stem.to_str().unwrap();
+
+        let first = write_new(stem, "first").unwrap();
+        let second = write_new(stem, "second").unwrap();
+        let third = write_new(stem, "third").unwrap();
+        let written: Vec<String> = [&first, &second, &third]
+            .iter()
+            .map(|path| fs::read_to_string(path).unwrap())
+            .collect();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(first, PathBuf::from(format!("{}.txt", stem)));
+        assert_eq!(second, PathBuf::from(format!("{}-2.txt", stem)));
+        assert_eq!(third, PathBuf::from(format!("{}-3.txt", stem)));
+        assert_eq!(written, ["first", "second", "third"]);
+    }
+}
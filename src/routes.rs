@@ -0,0 +1,430 @@
+use std::{fmt, net::Ipv4Addr};
+
+use crate::{
+    cidr::{self, Cidr},
+    classify::IpClass,
+    i18n::tr,
+};
+
+/// Route table dumps the importer recognizes.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Format {
+    /// Linux `ip route`.
+    IpRoute,
+    /// `netstat -rn` on Linux, BSD/macOS or Windows, and Windows `route print`.
+    Netstat,
+    /// Cisco IOS `show ip route`.
+    CiscoIos,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Format::IpRoute => "ip route",
+            Format::Netstat => "netstat -rn",
+            Format::CiscoIos => "show ip route",
+        })
+    }
+}
+
+/// The destinations of an imported route table.
+pub struct Import {
+    pub format: Format,
+    pub blocks: Vec<Cidr>,
+    /// Default routes left out, as a /0 would swallow every other block.
+    pub defaults: usize,
+}
+
+impl Import {
+    /// One block per line, ready for a list field.
+    pub fn to_list(&self) -> String {
+        let lines: Vec<String> = self.blocks.iter().map(ToString::to_string).collect();
+        lines.join("\n")
+    }
+
+    pub fn summary(&self) -> String {
+        if self.defaults > 0 {
+            tr!(
+                "routes-imported-without-default",
+                count = self.blocks.len(),
+                format = self.format
+            )
+        } else {
+            tr!(
+                "routes-imported",
+                count = self.blocks.len(),
+                format = self.format
+            )
+        }
+    }
+}
+
+/// Parses route table output into its destinations, or `None` when the
+/// text isn't in a format the importer knows.
+pub fn import(text: &str) -> Option<Import> {
+    let format = detect(text)?;
+    let routes = match format {
+        Format::IpRoute => text.lines().filter_map(ip_route_line).collect(),
+        Format::Netstat => netstat(text),
+        Format::CiscoIos => cisco(text),
+    };
+    let (defaults, blocks): (Vec<Cidr>, Vec<Cidr>) =
+        routes.into_iter().partition(|block| block.prefix == 0);
+    Some(Import {
+        format,
+        blocks,
+        defaults: defaults.len(),
+    })
+}
+
+/// Recognizes a format by its headers, or failing that by lines only it
+/// produces. A plain list of CIDRs matches nothing.
+pub fn detect(text: &str) -> Option<Format> {
+    let lines = || text.lines().map(str::trim);
+    if lines().any(|line| line.starts_with("Codes:") || line.starts_with("Gateway of last resort"))
+    {
+        return Some(Format::CiscoIos);
+    }
+    if lines().any(|line| netstat_columns(line).is_some() || line == "Kernel IP routing table") {
+        return Some(Format::Netstat);
+    }
+    if lines().any(|line| line.starts_with("default via") || line.contains(" dev ")) {
+        return Some(Format::IpRoute);
+    }
+    if lines().any(|line| {
+        cisco_route(line, None).is_some()
+            && (line.contains(" via ") || line.contains("is directly connected"))
+    }) {
+        return Some(Format::CiscoIos);
+    }
+    None
+}
+
+/// `192.168.1.0/24 dev eth0 proto kernel scope link src 192.168.1.10`,
+/// `default via 192.168.1.1 dev eth0`. A destination without a length is
+/// a host route.
+fn ip_route_line(line: &str) -> Option<Cidr> {
+    // Multipath `nexthop` lines are indented under their route.
+    if line.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let mut words = line.split_whitespace();
+    let mut destination = words.next()?;
+    if matches!(
+        destination,
+        "unicast"
+            | "local"
+            | "broadcast"
+            | "multicast"
+            | "anycast"
+            | "blackhole"
+            | "unreachable"
+            | "prohibit"
+            | "throw"
+            | "nat"
+    ) {
+        destination = words.next()?;
+    }
+    match destination {
+        "default" => Some(Cidr::new(Ipv4Addr::UNSPECIFIED, 0)),
+        destination => Cidr::parse(destination),
+    }
+}
+
+/// Where a netstat table keeps its destination mask.
+#[derive(Clone, Copy)]
+enum Columns {
+    /// Linux: `Destination Gateway Genmask Flags ...`.
+    Mask(usize),
+    /// BSD and macOS: `Destination Gateway Flags Netif ...`, with the
+    /// length folded into the destination.
+    Shorthand,
+}
+
+fn netstat_columns(line: &str) -> Option<Columns> {
+    let header: Vec<&str> = line.split_whitespace().collect();
+    match header.as_slice() {
+        ["Destination", "Gateway", "Genmask", ..] => Some(Columns::Mask(2)),
+        // Windows `route print`
+        ["Network", "Destination", "Netmask", ..] => Some(Columns::Mask(1)),
+        ["Destination", "Gateway", "Flags", ..] => Some(Columns::Shorthand),
+        _ => None,
+    }
+}
+
+/// Reads each table after its header line, skipping IPv6 tables. BSD
+/// gives those the same header as the IPv4 one.
+fn netstat(text: &str) -> Vec<Cidr> {
+    let mut columns = None;
+    let mut ipv6 = false;
+    let mut routes = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with("Internet6") || line.starts_with("IPv6 Route Table") {
+            ipv6 = true;
+            columns = None;
+        } else if line.starts_with("Internet:") || line.starts_with("IPv4 Route Table") {
+            ipv6 = false;
+        }
+        if let Some(found) = netstat_columns(line) {
+            columns = (!ipv6).then_some(found);
+            continue;
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let route = match (columns, words.as_slice()) {
+            (None, _) | (_, []) => None,
+            (Some(Columns::Mask(index)), words) => words
+                .get(index)
+                .and_then(|mask| mask.parse::<Ipv4Addr>().ok())
+                .and_then(cidr::mask_prefix)
+                .zip(words[0].parse::<Ipv4Addr>().ok())
+                .map(|(prefix, network)| Cidr::new(network, prefix)),
+            (Some(Columns::Shorthand), [destination, ..]) => bsd_destination(destination),
+        };
+        routes.extend(route);
+    }
+    routes
+}
+
+/// BSD writes `default`, drops trailing zero octets of a network
+/// (`192.168.1` is 192.168.1.0/24, `127` is 127.0.0.0/8) and gives full
+/// addresses without a length for host routes.
+fn bsd_destination(destination: &str) -> Option<Cidr> {
+    if destination == "default" {
+        return Some(Cidr::new(Ipv4Addr::UNSPECIFIED, 0));
+    }
+    let (address, length) = match destination.split_once('/') {
+        Some((address, length)) => (address, Some(length.parse::<u32>().ok()?)),
+        None => (address_only(destination), None),
+    };
+    let octets: Vec<u8> = address
+        .split('.')
+        .map(|octet| octet.parse().ok())
+        .collect::<Option<_>>()?;
+    if octets.is_empty() || octets.len() > 4 {
+        return None;
+    }
+    let mut padded = [0; 4];
+    padded[..octets.len()].copy_from_slice(&octets);
+    let prefix = length.unwrap_or(octets.len() as u32 * 8);
+    (prefix <= 32).then(|| Cidr::new(Ipv4Addr::from(padded), prefix))
+}
+
+/// Strips a `%interface` scope from a BSD destination.
+fn address_only(destination: &str) -> &str {
+    destination.split('%').next().unwrap_or(destination)
+}
+
+/// Cisco prints networks split into equal subnets under a header such as
+/// `172.16.0.0/24 is subnetted, 2 subnets`, and leaves the length off the
+/// routes below it.
+fn cisco(text: &str) -> Vec<Cidr> {
+    let mut subnetted = None;
+    let mut routes = Vec::new();
+    for line in text.lines() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if let [header, "is", "subnetted,", ..] = words.as_slice() {
+            subnetted = cidr::parse_cidr(header);
+            continue;
+        }
+        routes.extend(cisco_route(line, subnetted));
+    }
+    routes
+}
+
+/// `O E2  192.168.5.0/24 [110/20] via 10.1.1.2, 00:00:12, Gi0/1`: one to
+/// three route codes, then the destination. Legend, header and
+/// continuation lines have no codes before an address.
+fn cisco_route(line: &str, subnetted: Option<(Ipv4Addr, u32)>) -> Option<Cidr> {
+    let mut words = line.split_whitespace();
+    let mut codes = 0;
+    let destination = loop {
+        let word = words.next()?;
+        let is_code = word.len() <= 4
+            && word
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '*' | '+' | '%'));
+        if is_code && word.parse::<Ipv4Addr>().is_err() && codes < 3 {
+            codes += 1;
+            continue;
+        }
+        break word;
+    };
+    if codes == 0 {
+        return None;
+    }
+    if let Some((network, prefix)) = cidr::parse_cidr(destination) {
+        return Some(Cidr::new(network, prefix));
+    }
+    let network = destination.parse::<Ipv4Addr>().ok()?;
+    let classful = IpClass::of(network).default_prefix();
+    let prefix = match subnetted {
+        Some((major, length))
+            if cidr::network_of(major, classful) == cidr::network_of(network, classful) =>
+        {
+            length
+        }
+        _ => classful,
+    };
+    Some(Cidr::new(network, prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocks(import: &Import) -> Vec<String> {
+        import.blocks.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn ip_route() {
+        let text = "\
+default via 192.168.1.1 dev eth0 proto dhcp metric 100
+10.8.0.0/24 dev tun0 proto kernel scope link src 10.8.0.1
+192.168.1.0/24 dev eth0 proto kernel scope link src 192.168.1.10
+blackhole 10.99.0.0/16
+203.0.113.7 via 192.168.1.1 dev eth0
+10.20.0.0/16 proto static
+\tnexthop via 10.0.0.1 dev eth1 weight 1
+";
+        let import = import(text).unwrap();
+        assert!(import.format == Format::IpRoute);
+        assert_eq!(
+            blocks(&import),
+            [
+                "10.8.0.0/24",
+                "192.168.1.0/24",
+                "10.99.0.0/16",
+                "203.0.113.7/32",
+                "10.20.0.0/16",
+            ]
+        );
+        assert_eq!(import.defaults, 1);
+    }
+
+    #[test]
+    fn linux_netstat() {
+        let text = "\
+Kernel IP routing table
+Destination     Gateway         Genmask         Flags   MSS Window  irtt Iface
+0.0.0.0         192.168.1.1     0.0.0.0         UG        0 0          0 eth0
+172.17.0.0      0.0.0.0         255.255.0.0     U         0 0          0 docker0
+192.168.1.0     0.0.0.0         255.255.255.0   U         0 0          0 eth0
+";
+        let import = import(text).unwrap();
+        assert!(import.format == Format::Netstat);
+        assert_eq!(blocks(&import), ["172.17.0.0/16", "192.168.1.0/24"]);
+        assert_eq!(import.defaults, 1);
+    }
+
+    #[test]
+    fn windows_route_print() {
+        let text = "\
+IPv4 Route Table
+===========================================================================
+Active Routes:
+Network Destination        Netmask          Gateway       Interface  Metric
+          0.0.0.0          0.0.0.0      192.168.1.1    192.168.1.10     25
+        127.0.0.0        255.0.0.0         On-link         127.0.0.1    331
+      192.168.1.0    255.255.255.0         On-link      192.168.1.10    281
+===========================================================================
+Persistent Routes:
+  None
+
+IPv6 Route Table
+===========================================================================
+Active Routes:
+ If Metric Network Destination      Gateway
+  1    331 ::1/128                  On-link
+";
+        let import = import(text).unwrap();
+        assert!(import.format == Format::Netstat);
+        assert_eq!(blocks(&import), ["127.0.0.0/8", "192.168.1.0/24"]);
+        assert_eq!(import.defaults, 1);
+    }
+
+    #[test]
+    fn bsd_netstat() {
+        let text = "\
+Routing tables
+
+Internet:
+Destination        Gateway            Flags        Netif Expire
+default            192.168.1.1        UGScg          en0
+127                127.0.0.1          UCS            lo0
+127.0.0.1          127.0.0.1          UH             lo0
+169.254            link#4             UCS            en0      !
+192.168.1          link#4             UCS            en0      !
+192.168.1.1/32     link#4             UCS            en0      !
+10.0.0.0/8         192.168.1.1        UGSc           en0
+224.0.0/4          link#4             UmCS           en0      !
+
+Internet6:
+Destination                             Gateway                         Flags         Netif Expire
+default                                 fe80::%utun0                    UGcIg         utun0
+::1                                     ::1                             UHL             lo0
+";
+        let import = import(text).unwrap();
+        assert!(import.format == Format::Netstat);
+        assert_eq!(
+            blocks(&import),
+            [
+                "127.0.0.0/8",
+                "127.0.0.1/32",
+                "169.254.0.0/16",
+                "192.168.1.0/24",
+                "192.168.1.1/32",
+                "10.0.0.0/8",
+                "224.0.0.0/4",
+            ]
+        );
+        // The IPv6 table's default route isn't counted.
+        assert_eq!(import.defaults, 1);
+    }
+
+    #[test]
+    fn cisco_show_ip_route() {
+        let text = "\
+Codes: L - local, C - connected, S - static, R - RIP, M - mobile, B - BGP
+       D - EIGRP, EX - EIGRP external, O - OSPF, IA - OSPF inter area
+Gateway of last resort is 10.1.1.1 to network 0.0.0.0
+
+S*    0.0.0.0/0 [1/0] via 10.1.1.1
+      10.0.0.0/8 is variably subnetted, 2 subnets, 2 masks
+C        10.1.1.0/24 is directly connected, GigabitEthernet0/0
+L        10.1.1.2/32 is directly connected, GigabitEthernet0/0
+      172.16.0.0/24 is subnetted, 2 subnets
+O        172.16.1.0 [110/2] via 10.1.1.3, 00:00:12, GigabitEthernet0/0
+O IA     172.16.2.0 [110/3] via 10.1.1.3, 00:00:12, GigabitEthernet0/0
+D     192.168.5.0 [90/3072] via 10.1.1.4, 00:01:02, GigabitEthernet0/1
+";
+        let import = import(text).unwrap();
+        assert!(import.format == Format::CiscoIos);
+        assert_eq!(
+            blocks(&import),
+            [
+                "10.1.1.0/24",
+                "10.1.1.2/32",
+                "172.16.1.0/24",
+                "172.16.2.0/24",
+                "192.168.5.0/24",
+            ]
+        );
+        assert_eq!(import.defaults, 1);
+    }
+
+    #[test]
+    fn cisco_routes_without_the_legend() {
+        let text = "O E2  192.168.5.0/24 [110/20] via 10.1.1.2, 00:00:12, Gi0/1\n";
+        let import = import(text).unwrap();
+        assert!(import.format == Format::CiscoIos);
+        assert_eq!(blocks(&import), ["192.168.5.0/24"]);
+    }
+
+    #[test]
+    fn plain_list_is_not_a_route_table() {
+        assert!(detect("10.0.0.0/24\n192.168.1.0/24\n").is_none());
+        assert!(import("").is_none());
+    }
+}
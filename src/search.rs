@@ -0,0 +1,41 @@
+use std::ops::Range;
+
+use crate::input::Input;
+
+/// A `/` filter over a result list: only rows containing the query are
+/// shown, with the matches highlighted.
+pub struct ListFilter {
+    pub input: Input,
+    pub typing: bool,
+}
+
+impl ListFilter {
+    pub fn new() -> Self {
+        Self {
+            input: Input::default(),
+            typing: false,
+        }
+    }
+
+    /// The query rows must contain, once one is typed.
+    pub fn query(&self) -> Option<&str> {
+        Some(self.input.trim()).filter(|q| !q.is_empty())
+    }
+
+    pub fn keeps(&self, text: &str) -> bool {
+        self.query()
+            .is_none_or(|query| !match_ranges(text, query).is_empty())
+    }
+}
+
+/// Byte ranges where `query` occurs in `text`, ignoring ASCII case.
+pub fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    let text = text.to_ascii_lowercase();
+    let query = query.to_ascii_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    text.match_indices(&query)
+        .map(|(start, m)| start..start + m.len())
+        .collect()
+}
@@ -0,0 +1,156 @@
+use std::{
+    fs, io,
+    net::Ipv4Addr,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{cloud::Cloud, hosts::Labels, i18n::tr};
+
+/// Everything needed to rebuild the app; results are recalculated on load.
+#[derive(Serialize, Deserialize)]
+pub struct Session {
+    pub active: usize,
+    pub workspaces: Vec<WorkspaceState>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct WorkspaceState {
+    pub ip_input: String,
+    pub subnet_input: String,
+    pub hosts_input: String,
+    pub subnets_input: String,
+    pub history: Vec<(Ipv4Addr, Ipv4Addr)>,
+    pub membership_ip: String,
+    pub membership_cidr: String,
+    pub overlap_list: String,
+    pub overlap_path: String,
+    pub tree_parent: String,
+    pub tree_children: String,
+    pub range_input: String,
+    pub ipv6_input: String,
+    pub ipv6_mac: String,
+    pub ipv6_prefix: String,
+    pub arithmetic_input: String,
+    pub cleanup_list: String,
+    pub dhcp_static_percent: Option<u32>,
+    pub cloud: Option<Cloud>,
+    pub labels: Labels,
+}
+
+pub enum PromptKind {
+    Save,
+    Load,
+}
+
+/// The save/load popup: a name field plus the existing sessions to pick from.
+pub struct SessionPrompt {
+    pub kind: PromptKind,
+    pub name: String,
+    pub files: Vec<String>,
+    pub selected: usize,
+}
+
+impl SessionPrompt {
+    pub fn new(kind: PromptKind) -> Self {
+        Self {
+            kind,
+            name: String::new(),
+            files: list_sessions(),
+            selected: 0,
+        }
+    }
+
+    /// Sessions whose name contains the typed text.
+    pub fn matches(&self) -> Vec<&str> {
+        self.files
+            .iter()
+            .map(String::as_str)
+            .filter(|f| f.contains(self.name.trim()))
+            .collect()
+    }
+
+    /// The session name to act on: the typed name when saving, otherwise
+    /// the highlighted match.
+    pub fn chosen(&self) -> Option<String> {
+        match self.kind {
+            PromptKind::Save if !self.name.trim().is_empty() => Some(self.name.trim().to_string()),
+            PromptKind::Save => None,
+            PromptKind::Load => self.matches().get(self.selected).map(|s| s.to_string()),
+        }
+    }
+}
+
+/// `$XDG_DATA_HOME/subnet_calculator_tui`, falling back to
+/// `~/.local/share` and finally the working directory. Sessions and the
+/// debug log live here.
+pub fn data_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("subnet_calculator_tui")
+}
+
+pub fn session_dir() -> PathBuf {
+    data_dir().join("sessions")
+}
+
+/// The file for session `name`. Names that could reach outside the
+/// session directory are refused.
+fn session_path(name: &str) -> io::Result<PathBuf> {
+    if name.is_empty() || name.contains(['/', '\\']) || name.contains("..") {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            tr!("error-session-name", name = name),
+        ));
+    }
+    Ok(session_dir().join(format!("{}.json", name)))
+}
+
+pub fn list_sessions() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(session_dir()) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "json").then(|| path.file_stem()?.to_str().map(String::from))?
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+pub fn save(name: &str, session: &Session) -> io::Result<PathBuf> {
+    let path = session_path(name)?;
+    fs::create_dir_all(session_dir())?;
+    fs::write(&path, serde_json::to_string_pretty(session)?)?;
+    Ok(path)
+}
+
+pub fn load(name: &str) -> io::Result<Session> {
+    let contents = fs::read_to_string(session_path(name)?)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_names_stay_in_the_session_directory() {
+        for name in ["lab", "site-2_core", "v1.2"] {
+            assert_eq!(
+                session_path(name).unwrap(),
+                session_dir().join(format!("{}.json", name))
+            );
+        }
+        for name in ["", "../lab", "a/b", "a\\b", "..", "/etc/passwd"] {
+            let err = session_path(name).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{name:?}");
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind},
+    net::{Ipv4Addr, SocketAddr, TcpStream},
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, Receiver},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{i18n::tr, search::ListFilter};
+
+/// Largest number of hosts a sweep will take on, one /16.
+pub const MAX_SWEEP_HOSTS: u64 = 65536;
+
+const TIMEOUT: Duration = Duration::from_millis(800);
+
+/// Probes in flight at once. Most of a probe is spent waiting on the
+/// timeout, so this bounds sockets and `ping` processes rather than CPU.
+const WORKERS: usize = 64;
+
+/// How a host is probed. ICMP runs the system `ping`, since raw sockets
+/// need privileges; TCP counts any answer on the port, even a refused
+/// connection, as the host being up. `Port` tells open ports from closed
+/// ones instead.
+#[derive(Clone, Copy, Debug)]
+pub enum Probe {
+    Icmp,
+    Tcp(u16),
+    Port(u16),
+}
+
+impl Probe {
+    /// What a positive outcome is called in summaries.
+    pub fn answer_label(self) -> String {
+        match self {
+            Probe::Icmp | Probe::Tcp(_) => tr!("sweep-up"),
+            Probe::Port(_) => tr!("sweep-open"),
+        }
+    }
+}
+
+impl fmt::Display for Probe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Probe::Icmp => f.write_str("ICMP"),
+            Probe::Tcp(port) => write!(f, "TCP {}", port),
+            Probe::Port(port) => f.write_str(&tr!("sweep-probe-port", port = port)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Outcome {
+    Pending,
+    Up(Duration),
+    Open(Duration),
+    /// The host refused the connection: it is there, the port is not.
+    Closed,
+    Down,
+}
+
+/// A sweep run by a pool of worker threads that take hosts off a shared
+/// counter and report each outcome over a channel each tick drains.
+pub struct Sweep {
+    pub probe: Probe,
+    pub targets: Vec<Ipv4Addr>,
+    pub results: Vec<Outcome>,
+    pub finished: usize,
+    pub scroll: usize,
+    pub filter: ListFilter,
+    pub started: Instant,
+    /// When the last outcome arrived or the sweep was cancelled.
+    pub stopped: Option<Instant>,
+    pub cancelled: bool,
+    receiver: Receiver<(usize, Outcome)>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Sweep {
+    pub fn start(targets: Vec<Ipv4Addr>, probe: Probe) -> Self {
+        tracing::info!(hosts = targets.len(), ?probe, "sweep started");
+        let (sender, receiver) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let jobs = Arc::new(targets.clone());
+        let next = Arc::new(AtomicUsize::new(0));
+        for _ in 0..WORKERS.min(targets.len()) {
+            let (jobs, next, stop, sender) = (
+                Arc::clone(&jobs),
+                Arc::clone(&next),
+                Arc::clone(&cancel),
+                sender.clone(),
+            );
+            thread::spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= jobs.len() || stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                if sender.send((i, run_probe(jobs[i], probe))).is_err() {
+                    break;
+                }
+            });
+        }
+        Self {
+            probe,
+            results: vec![Outcome::Pending; targets.len()],
+            targets,
+            finished: 0,
+            scroll: 0,
+            filter: ListFilter::new(),
+            started: Instant::now(),
+            stopped: None,
+            cancelled: false,
+            receiver,
+            cancel,
+        }
+    }
+
+    /// Applies every outcome received since the last call.
+    pub fn poll(&mut self) {
+        while let Ok((i, outcome)) = self.receiver.try_recv() {
+            self.results[i] = outcome;
+            self.finished += 1;
+            if self.finished == self.targets.len() {
+                self.stopped = Some(Instant::now());
+                tracing::info!(
+                    hosts = self.finished,
+                    answered = self.answered(),
+                    "sweep finished"
+                );
+            }
+        }
+    }
+
+    /// Stops handing out hosts. Probes already in flight finish and are
+    /// still recorded.
+    pub fn cancel(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+        tracing::info!(probed = self.finished, "sweep cancelled");
+        self.cancelled = true;
+        self.stopped.get_or_insert_with(Instant::now);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.stopped.is_none()
+    }
+
+    /// Hosts probed per second so far.
+    pub fn rate(&self) -> f64 {
+        let elapsed = self.stopped.unwrap_or_else(Instant::now) - self.started;
+        self.finished as f64 / elapsed.as_secs_f64().max(0.001)
+    }
+
+    /// Hosts that answered the probe: up for a sweep, open for a port scan.
+    pub fn answered(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|o| matches!(o, Outcome::Up(_) | Outcome::Open(_)))
+            .count()
+    }
+}
+
+impl Drop for Sweep {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+fn run_probe(ip: Ipv4Addr, probe: Probe) -> Outcome {
+    let start = Instant::now();
+    match probe {
+        Probe::Icmp => {
+            let answered = ping_command(ip)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|status| status.success());
+            if answered {
+                Outcome::Up(start.elapsed())
+            } else {
+                Outcome::Down
+            }
+        }
+        Probe::Tcp(port) => match connect(ip, port) {
+            Ok(_) => Outcome::Up(start.elapsed()),
+            Err(err) if err.kind() == ErrorKind::ConnectionRefused => Outcome::Up(start.elapsed()),
+            Err(_) => Outcome::Down,
+        },
+        Probe::Port(port) => match connect(ip, port) {
+            Ok(_) => Outcome::Open(start.elapsed()),
+            Err(err) if err.kind() == ErrorKind::ConnectionRefused => Outcome::Closed,
+            Err(_) => Outcome::Down,
+        },
+    }
+}
+
+fn connect(ip: Ipv4Addr, port: u16) -> io::Result<TcpStream> {
+    TcpStream::connect_timeout(&SocketAddr::from((ip, port)), TIMEOUT)
+}
+
+#[cfg(windows)]
+fn ping_command(ip: Ipv4Addr) -> Command {
+    let mut command = Command::new("ping");
+    command.args(["-n", "1", "-w", "1000", &ip.to_string()]);
+    command
+}
+
+#[cfg(not(windows))]
+fn ping_command(ip: Ipv4Addr) -> Command {
+    let mut command = Command::new("ping");
+    command.args(["-c", "1", "-W", "1", &ip.to_string()]);
+    command
+}
@@ -0,0 +1,85 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// Toasts on screen at once; the rest wait their turn.
+pub const MAX_VISIBLE: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Level {
+    Info,
+    Error,
+}
+
+pub struct Toast {
+    pub message: String,
+    pub level: Level,
+    /// When it first appeared; queued toasts start their timer only once
+    /// they are visible.
+    shown: Option<Instant>,
+}
+
+impl Toast {
+    fn lifetime(&self) -> Duration {
+        match self.level {
+            Level::Info => Duration::from_secs(4),
+            Level::Error => Duration::from_secs(8),
+        }
+    }
+}
+
+/// Transient messages for outcomes that aren't worth interrupting for.
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self {
+            queue: VecDeque::new(),
+        }
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::info!("{}", message);
+        self.push(message, Level::Info);
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::warn!("{}", message);
+        self.push(message, Level::Error);
+    }
+
+    fn push(&mut self, message: String, level: Level) {
+        self.queue.push_back(Toast {
+            message,
+            level,
+            shown: None,
+        });
+    }
+
+    /// Takes every queued toast, for a front end that prints them instead.
+    pub fn drain(&mut self) -> impl Iterator<Item = Toast> + '_ {
+        self.queue.drain(..)
+    }
+
+    /// Starts the timers of newly visible toasts and drops expired ones.
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        for toast in self.queue.iter_mut().take(MAX_VISIBLE) {
+            toast.shown.get_or_insert(now);
+        }
+        self.queue.retain(|toast| {
+            toast
+                .shown
+                .is_none_or(|shown| now - shown < toast.lifetime())
+        });
+    }
+
+    pub fn visible(&self) -> impl Iterator<Item = &Toast> {
+        self.queue.iter().take(MAX_VISIBLE)
+    }
+}
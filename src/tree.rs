@@ -0,0 +1,303 @@
+use std::net::Ipv4Addr;
+
+use crate::{cidr::Cidr, i18n::tr, input::Input, overlap, range};
+
+pub enum TreeInput {
+    Parent,
+    Children,
+    NoTyping,
+}
+
+pub struct TreeNode {
+    pub block: Cidr,
+    pub children: Vec<TreeNode>,
+    pub collapsed: bool,
+}
+
+/// A visible line of the flattened tree.
+pub struct TreeRow {
+    pub depth: usize,
+    pub path: Vec<usize>,
+    pub block: Cidr,
+    pub free: u64,
+    pub has_children: bool,
+    pub collapsed: bool,
+}
+
+impl TreeNode {
+    fn leaf(block: Cidr) -> Self {
+        Self {
+            block,
+            children: Vec::new(),
+            collapsed: false,
+        }
+    }
+
+    /// Builds the containment tree under `parent`. Blocks outside the parent
+    /// are returned separately.
+    pub fn build(parent: Cidr, blocks: &[Cidr]) -> (Self, Vec<Cidr>) {
+        let mut sorted = blocks.to_vec();
+        sorted.sort();
+        sorted.dedup();
+
+        let mut root = Self::leaf(parent);
+        let mut outside = Vec::new();
+        for block in sorted {
+            if parent.contains(&block) {
+                root.insert(block);
+            } else {
+                outside.push(block);
+            }
+        }
+        (root, outside)
+    }
+
+    // Blocks arrive sorted, so an enclosing block is always inserted before
+    // anything it contains.
+    fn insert(&mut self, block: Cidr) {
+        if block == self.block {
+            return;
+        }
+        match self.children.iter_mut().find(|c| c.block.contains(&block)) {
+            Some(child) => child.insert(block),
+            None => self.children.push(Self::leaf(block)),
+        }
+    }
+
+    /// Addresses not covered by any direct child.
+    pub fn free(&self) -> u64 {
+        self.block.size() - self.children.iter().map(|c| c.block.size()).sum::<u64>()
+    }
+
+    /// The free space between direct children, as the fewest blocks.
+    pub fn gaps(&self) -> Vec<Cidr> {
+        let mut gaps = Vec::new();
+        let mut next = u64::from(self.block.first());
+        let end = u64::from(self.block.last()) + 1;
+        for child in &self.children {
+            let start = u64::from(child.block.first());
+            if start > next {
+                gaps.extend(range::range_to_cidrs(
+                    Ipv4Addr::from(next as u32),
+                    Ipv4Addr::from((start - 1) as u32),
+                ));
+            }
+            next = u64::from(child.block.last()) + 1;
+        }
+        if next < end {
+            gaps.extend(range::range_to_cidrs(
+                Ipv4Addr::from(next as u32),
+                Ipv4Addr::from((end - 1) as u32),
+            ));
+        }
+        gaps
+    }
+
+    /// How much of each of `cells` equal slices of the block direct
+    /// children take up, from 0.0 (free) to 1.0 (allocated).
+    pub fn usage(&self, cells: usize) -> Vec<f64> {
+        let first = u64::from(self.block.first());
+        let size = self.block.size() as f64;
+        (0..cells)
+            .map(|i| {
+                let start = first as f64 + size * i as f64 / cells as f64;
+                let end = first as f64 + size * (i + 1) as f64 / cells as f64;
+                let used: f64 = self
+                    .children
+                    .iter()
+                    .map(|child| {
+                        let child_start = f64::from(child.block.first());
+                        let child_end = child_start + child.block.size() as f64;
+                        (end.min(child_end) - start.max(child_start)).max(0.0)
+                    })
+                    .sum();
+                used / (end - start)
+            })
+            .collect()
+    }
+
+    pub fn node(&self, path: &[usize]) -> Option<&TreeNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&i, rest)) => self.children.get(i)?.node(rest),
+        }
+    }
+
+    pub fn node_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&i, rest)) => self.children.get_mut(i)?.node_mut(rest),
+        }
+    }
+
+    pub fn rows(&self) -> Vec<TreeRow> {
+        let mut rows = Vec::new();
+        self.collect_rows(0, &mut Vec::new(), &mut rows);
+        rows
+    }
+
+    fn collect_rows(&self, depth: usize, path: &mut Vec<usize>, rows: &mut Vec<TreeRow>) {
+        rows.push(TreeRow {
+            depth,
+            path: path.clone(),
+            block: self.block,
+            free: self.free(),
+            has_children: !self.children.is_empty(),
+            collapsed: self.collapsed,
+        });
+        if self.collapsed {
+            return;
+        }
+        for (i, child) in self.children.iter().enumerate() {
+            path.push(i);
+            child.collect_rows(depth + 1, path, rows);
+            path.pop();
+        }
+    }
+}
+
+pub struct TreeView {
+    pub parent_input: Input,
+    pub children_input: Input,
+    pub input_mode: TreeInput,
+    pub root: Option<TreeNode>,
+    pub outside: Vec<Cidr>,
+    pub invalid: Vec<String>,
+    pub selected: usize,
+    pub error: Option<String>,
+}
+
+impl TreeView {
+    pub fn new() -> Self {
+        Self {
+            parent_input: Input::default(),
+            children_input: Input::default(),
+            input_mode: TreeInput::NoTyping,
+            root: None,
+            outside: Vec::new(),
+            invalid: Vec::new(),
+            selected: 0,
+            error: None,
+        }
+    }
+
+    pub fn build(&mut self) {
+        let Some(parent) = Cidr::parse(&self.parent_input) else {
+            self.root = None;
+            self.error = Some(tr!("tree-invalid-parent"));
+            return;
+        };
+        let (children, invalid) = overlap::parse_list(&self.children_input);
+        let (root, outside) = TreeNode::build(parent, &children);
+        self.root = Some(root);
+        self.outside = outside;
+        self.invalid = invalid;
+        self.selected = 0;
+        self.error = None;
+    }
+
+    pub fn rows(&self) -> Vec<TreeRow> {
+        self.root.as_ref().map(TreeNode::rows).unwrap_or_default()
+    }
+
+    /// The node under the cursor.
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        let row = self.rows().into_iter().nth(self.selected)?;
+        self.root.as_ref()?.node(&row.path)
+    }
+
+    pub fn toggle_selected(&mut self) {
+        let Some(row) = self.rows().into_iter().nth(self.selected) else {
+            return;
+        };
+        if let Some(node) = self.root.as_mut().and_then(|r| r.node_mut(&row.path)) {
+            node.collapsed = !node.collapsed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cidr(block: &str) -> Cidr {
+        Cidr::parse(block).unwrap()
+    }
+
+    /// 10.0.0.0/24 with a gap at the start, one between its two children
+    /// and one at the end; the /26 child holds a /27 of its own.
+    fn tree() -> (TreeNode, Vec<Cidr>) {
+        let children = [
+            "10.0.0.160/27",
+            "10.0.0.64/27",
+            "10.0.1.0/24",
+            "10.0.0.64/26",
+            "10.0.0.160/27",
+        ];
+        let blocks: Vec<Cidr> = children.iter().map(|b| cidr(b)).collect();
+        TreeNode::build(cidr("10.0.0.0/24"), &blocks)
+    }
+
+    #[test]
+    fn build_nests_blocks_and_sets_aside_outsiders() {
+        let (root, outside) = tree();
+        assert_eq!(outside, [cidr("10.0.1.0/24")]);
+        let rows: Vec<(usize, String)> = root
+            .rows()
+            .iter()
+            .map(|row| (row.depth, row.block.to_string()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                (0, "10.0.0.0/24".to_string()),
+                (1, "10.0.0.64/26".to_string()),
+                (2, "10.0.0.64/27".to_string()),
+                (1, "10.0.0.160/27".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn free_counts_what_direct_children_leave() {
+        let (root, _) = tree();
+        assert_eq!(root.free(), 256 - 64 - 32);
+        assert_eq!(root.children[0].free(), 32);
+        // A leaf is all free.
+        assert_eq!(root.children[1].free(), 32);
+    }
+
+    #[test]
+    fn gaps_at_the_start_middle_and_end() {
+        let (root, _) = tree();
+        assert_eq!(
+            root.gaps(),
+            [
+                cidr("10.0.0.0/26"),
+                cidr("10.0.0.128/27"),
+                cidr("10.0.0.192/26"),
+            ]
+        );
+        assert_eq!(root.children[0].gaps(), [cidr("10.0.0.96/27")]);
+        assert_eq!(
+            TreeNode::build(cidr("0.0.0.0/0"), &[]).0.gaps(),
+            [cidr("0.0.0.0/0")]
+        );
+    }
+
+    #[test]
+    fn collapsed_nodes_hide_their_children() {
+        let (mut root, _) = tree();
+        root.node_mut(&[0]).unwrap().collapsed = true;
+        assert_eq!(root.rows().len(), 3);
+        assert_eq!(root.node(&[0, 0]).unwrap().block, cidr("10.0.0.64/27"));
+    }
+
+    #[test]
+    fn usage_shades_the_slices_children_cover() {
+        let (root, _) = tree();
+        assert_eq!(root.usage(4), [0.0, 1.0, 0.5, 0.0]);
+        assert_eq!(root.usage(8), [0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(root.children[1].usage(2), [0.0, 0.0]);
+    }
+}
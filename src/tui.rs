@@ -0,0 +1,226 @@
+use std::{io, panic, thread};
+
+use ratatui::Terminal;
+
+use crate::event::Event;
+
+#[cfg(not(any(feature = "crossterm", feature = "termion")))]
+compile_error!("enable the crossterm or termion feature for a terminal backend");
+
+pub use backend::Backend;
+
+/// The terminal in raw mode on the alternate screen for as long as it
+/// lives. Dropping it restores the terminal, so early returns through `?`
+/// and unwinding panics leave the shell usable too.
+pub struct Tui {
+    pub terminal: Terminal<Backend>,
+}
+
+impl Tui {
+    pub fn enter() -> io::Result<Self> {
+        Ok(Self {
+            terminal: Terminal::new(backend::enter()?)?,
+        })
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        backend::restore();
+    }
+}
+
+/// Restores the terminal before the default hook prints a panic on the
+/// main thread, so the message lands on the normal screen instead of being
+/// wiped with the alternate one. The app keeps running through a panic on
+/// a worker thread, such as a sweep, so that one only goes to the log
+/// rather than across the screen. Call it from the main thread.
+pub fn install_panic_hook() {
+    let default = panic::take_hook();
+    let main = thread::current().id();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().id() == main {
+            backend::restore();
+            default(info);
+        } else {
+            tracing::error!("{}", info);
+        }
+    }));
+}
+
+/// Terminal input as app events, blocking until each arrives. Input the
+/// app has no use for, such as mouse events, is skipped.
+pub fn events() -> impl Iterator<Item = io::Result<Event>> {
+    backend::events()
+}
+
+#[cfg(feature = "crossterm")]
+mod backend {
+    use std::{
+        io::{self, Stdout},
+        iter,
+    };
+
+    use crossterm::{
+        cursor::Show,
+        event::{self as terminal, DisableBracketedPaste, EnableBracketedPaste},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    };
+    use ratatui::backend::CrosstermBackend;
+
+    use crate::{
+        event::Event,
+        key::{KeyCode, KeyEvent, KeyModifiers},
+    };
+
+    pub type Backend = CrosstermBackend<Stdout>;
+
+    pub fn enter() -> io::Result<Backend> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, EnableBracketedPaste)?;
+        Ok(CrosstermBackend::new(io::stdout()))
+    }
+
+    /// Leaves raw mode and the alternate screen. Safe to call more than
+    /// once.
+    pub fn restore() {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableBracketedPaste,
+            Show
+        );
+    }
+
+    pub fn events() -> impl Iterator<Item = io::Result<Event>> {
+        iter::from_fn(|| loop {
+            let event = match terminal::read() {
+                Ok(terminal::Event::Key(key)) => key_event(key).map(Event::Key),
+                Ok(terminal::Event::Paste(text)) => Some(Event::Paste(text)),
+                Ok(terminal::Event::Resize(width, height)) => Some(Event::Resize(width, height)),
+                Ok(_) => None,
+                Err(err) => return Some(Err(err)),
+            };
+            if let Some(event) = event {
+                return Some(Ok(event));
+            }
+        })
+    }
+
+    fn key_event(key: terminal::KeyEvent) -> Option<KeyEvent> {
+        let code = match key.code {
+            terminal::KeyCode::Backspace => KeyCode::Backspace,
+            terminal::KeyCode::Enter => KeyCode::Enter,
+            terminal::KeyCode::Left => KeyCode::Left,
+            terminal::KeyCode::Right => KeyCode::Right,
+            terminal::KeyCode::Up => KeyCode::Up,
+            terminal::KeyCode::Down => KeyCode::Down,
+            terminal::KeyCode::Home => KeyCode::Home,
+            terminal::KeyCode::End => KeyCode::End,
+            terminal::KeyCode::PageUp => KeyCode::PageUp,
+            terminal::KeyCode::PageDown => KeyCode::PageDown,
+            terminal::KeyCode::Tab => KeyCode::Tab,
+            terminal::KeyCode::BackTab => KeyCode::BackTab,
+            terminal::KeyCode::Delete => KeyCode::Delete,
+            terminal::KeyCode::Insert => KeyCode::Insert,
+            terminal::KeyCode::F(n) => KeyCode::F(n),
+            terminal::KeyCode::Char(c) => KeyCode::Char(c),
+            terminal::KeyCode::Esc => KeyCode::Esc,
+            _ => return None,
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for (theirs, ours) in [
+            (terminal::KeyModifiers::SHIFT, KeyModifiers::SHIFT),
+            (terminal::KeyModifiers::CONTROL, KeyModifiers::CONTROL),
+            (terminal::KeyModifiers::ALT, KeyModifiers::ALT),
+        ] {
+            modifiers.set(ours, key.modifiers.contains(theirs));
+        }
+        Some(KeyEvent::new(code, modifiers))
+    }
+}
+
+/// Termion has no resize or paste events: ratatui notices a new size on
+/// the next draw, and pasted text arrives as ordinary keys.
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+mod backend {
+    use std::{
+        io::{self, Stdout, Write},
+        sync::Mutex,
+    };
+
+    use ratatui::backend::TermionBackend;
+    use termion::{
+        cursor::Show,
+        event::{Event as TermionEvent, Key},
+        input::TermRead,
+        raw::{IntoRawMode, RawTerminal},
+        screen::{AlternateScreen, IntoAlternateScreen, ToMainScreen},
+    };
+
+    use crate::{
+        event::Event,
+        key::{KeyCode, KeyEvent, KeyModifiers},
+    };
+
+    pub type Backend = TermionBackend<AlternateScreen<Stdout>>;
+
+    /// Holds the terminal settings from before raw mode, put back when it
+    /// drops. Kept apart from the backend so the panic hook can reach it.
+    static RAW: Mutex<Option<RawTerminal<Stdout>>> = Mutex::new(None);
+
+    pub fn enter() -> io::Result<Backend> {
+        let raw = io::stdout().into_raw_mode()?;
+        if let Ok(mut slot) = RAW.lock() {
+            *slot = Some(raw);
+        }
+        Ok(TermionBackend::new(io::stdout().into_alternate_screen()?))
+    }
+
+    /// Brings back the main screen and cursor and leaves raw mode. Safe to
+    /// call more than once.
+    pub fn restore() {
+        let mut stdout = io::stdout();
+        let _ = write!(stdout, "{}{}", ToMainScreen, Show);
+        let _ = stdout.flush();
+        if let Ok(mut slot) = RAW.lock() {
+            slot.take();
+        }
+    }
+
+    pub fn events() -> impl Iterator<Item = io::Result<Event>> {
+        io::stdin().events().filter_map(|event| match event {
+            Ok(TermionEvent::Key(key)) => key_event(key).map(|key| Ok(Event::Key(key))),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+    }
+
+    fn key_event(key: Key) -> Option<KeyEvent> {
+        let (code, modifiers) = match key {
+            Key::Backspace => (KeyCode::Backspace, KeyModifiers::NONE),
+            Key::Left => (KeyCode::Left, KeyModifiers::NONE),
+            Key::Right => (KeyCode::Right, KeyModifiers::NONE),
+            Key::Up => (KeyCode::Up, KeyModifiers::NONE),
+            Key::Down => (KeyCode::Down, KeyModifiers::NONE),
+            Key::Home => (KeyCode::Home, KeyModifiers::NONE),
+            Key::End => (KeyCode::End, KeyModifiers::NONE),
+            Key::PageUp => (KeyCode::PageUp, KeyModifiers::NONE),
+            Key::PageDown => (KeyCode::PageDown, KeyModifiers::NONE),
+            Key::BackTab => (KeyCode::BackTab, KeyModifiers::SHIFT),
+            Key::Delete => (KeyCode::Delete, KeyModifiers::NONE),
+            Key::Insert => (KeyCode::Insert, KeyModifiers::NONE),
+            Key::F(n) => (KeyCode::F(n), KeyModifiers::NONE),
+            Key::Char('\n') => (KeyCode::Enter, KeyModifiers::NONE),
+            Key::Char('\t') => (KeyCode::Tab, KeyModifiers::NONE),
+            Key::Char(c) => (KeyCode::Char(c), KeyModifiers::NONE),
+            Key::Alt(c) => (KeyCode::Char(c), KeyModifiers::ALT),
+            Key::Ctrl(c) => (KeyCode::Char(c), KeyModifiers::CONTROL),
+            Key::Esc => (KeyCode::Esc, KeyModifiers::NONE),
+            _ => return None,
+        };
+        Some(KeyEvent::new(code, modifiers))
+    }
+}
@@ -0,0 +1,2012 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Row, Table, Tabs, Wrap,
+    },
+    Frame,
+};
+use std::{net::Ipv4Addr, rc::Rc};
+
+use crate::{
+    app::{App, InputMode, Screen, SidePane, Workspace},
+    cidr, compare, explain,
+    export::{self, ExportPicker},
+    geoip::GeoIp,
+    hosts::HostPrompt,
+    i18n::tr,
+    interfaces::InterfacePicker,
+    ipv6::{self, Ipv6Input},
+    keymap::{Action, Context, Keymap},
+    logging,
+    membership::{Membership, MembershipInput},
+    octet::OctetCursor,
+    overlap::{OverlapInput, Relation},
+    palette::Palette,
+    search::{self, ListFilter},
+    session::{self, PromptKind, SessionPrompt},
+    sweep::{Outcome, Probe},
+    toast::{Level, Toasts},
+    tree::{TreeInput, TreeNode},
+};
+
+/// Below this size screens can't be drawn without clipping.
+const MIN_WIDTH: u16 = 50;
+const MIN_HEIGHT: u16 = 20;
+/// From this width the calculator puts its panels side by side: the side
+/// column plus 60 columns, about what the subnetting and DHCP results need
+/// before they wrap. Narrower, the panels stack, which between this and
+/// `NARROW_WIDTH` still leaves room for the history.
+const WIDE_WIDTH: u16 = SIDE_COLUMN_WIDTH + 60;
+/// Below this width the calculator drops its history.
+const NARROW_WIDTH: u16 = 80;
+/// Fits the binary view, the widest panel beside the results.
+const SIDE_COLUMN_WIDTH: u16 = 50;
+/// Results narrower than this are covered by a side pane instead.
+const MIN_RESULTS_WIDTH: u16 = 40;
+
+pub fn draw(f: &mut Frame, app: &App) {
+    draw_screen(f, app);
+    if app.no_color {
+        monochrome(f.buffer_mut());
+    }
+}
+
+/// Restyles a drawn frame without color: highlighted cells in reverse
+/// video, colored text in bold and box drawing in ASCII, so nothing relies
+/// on telling colors apart.
+fn monochrome(buf: &mut Buffer) {
+    for cell in &mut buf.content {
+        let emphasis = if cell.bg != Color::Reset {
+            Modifier::REVERSED
+        } else if matches!(cell.fg, Color::Reset | Color::DarkGray) {
+            Modifier::empty()
+        } else {
+            Modifier::BOLD
+        };
+        cell.modifier |= emphasis;
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+        let ascii = match cell.symbol() {
+            "─" => "-",
+            "│" => "|",
+            "┌" | "┐" | "└" | "┘" | "├" | "┤" | "┬" | "┴" | "┼" => "+",
+            "█" => "#",
+            "▒" => "+",
+            "·" => ".",
+            "•" => "*",
+            "▾" => "v",
+            "▸" => ">",
+            _ => continue,
+        };
+        cell.set_symbol(ascii);
+    }
+}
+
+fn draw_screen(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        draw_too_small(f, area);
+        return;
+    }
+    let chunks = screen_chunks(area, app.debug_log);
+
+    let titles: Vec<String> = app
+        .workspaces
+        .iter()
+        .enumerate()
+        .map(|(i, ws)| format!("{}: {}", i + 1, ws.label()))
+        .collect();
+    let tabs = Tabs::new(titles)
+        .select(app.active)
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow));
+    f.render_widget(tabs, chunks[0]);
+
+    let ws = app.workspace();
+    let keys = &app.keymap;
+    if ws.zoomed {
+        let hint = tr!(
+            "zoom-hint",
+            key = keys.hint(ws.context(), Action::ToggleZoom)
+        );
+        let hint = Paragraph::new(hint)
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Right);
+        f.render_widget(hint, chunks[0]);
+    }
+    let area = chunks[1];
+    match ws.screen {
+        Screen::Calculator => draw_calculator(f, area, ws, keys, &app.geoip),
+        Screen::Membership => draw_membership(f, area, ws, keys),
+        Screen::Overlap => draw_overlap(f, area, ws, keys),
+        Screen::Tree => draw_tree(f, area, ws, keys),
+        Screen::Range => draw_range(f, area, ws, keys),
+        Screen::Hosts => draw_hosts(f, area, ws, keys),
+        Screen::Quiz => draw_quiz(f, area, ws, keys),
+        Screen::Sweep => draw_sweep(f, area, ws, keys),
+        Screen::Ipv6 => draw_ipv6(f, area, ws, keys),
+        Screen::Arithmetic => draw_arithmetic(f, area, ws, keys),
+        Screen::Cleanup => draw_cleanup(f, area, ws, keys),
+        Screen::Explain => draw_explain(f, area, ws, keys),
+    }
+
+    if let Some(line) = &app.command_line {
+        let row = Rect::new(area.x, area.bottom().saturating_sub(1), area.width, 1);
+        f.render_widget(Clear, row);
+        f.render_widget(
+            Paragraph::new(format!(":{}", line)).style(Style::default().fg(Color::Yellow)),
+            row,
+        );
+    }
+    if let Some(picker) = &app.interfaces {
+        draw_interfaces(f, picker);
+    }
+    if let Some(picker) = &app.export {
+        draw_export(f, picker);
+    }
+    if let Some(palette) = &app.palette {
+        draw_palette(f, palette, keys, ws.context());
+    }
+    if let Some(prompt) = &app.session_prompt {
+        draw_session_prompt(f, prompt);
+    }
+    if app.debug_log {
+        draw_debug_log(f, chunks[2]);
+    }
+    draw_toasts(f, &app.toasts);
+}
+
+/// The tab bar, the screen and the debug log below it.
+fn screen_chunks(area: Rect, debug_log: bool) -> Rc<[Rect]> {
+    let log_height = if debug_log { 10 } else { 0 };
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(log_height),
+        ])
+        .split(area)
+}
+
+/// Splits `area` like a `Layout`, except that a zoomed workspace gives
+/// all of it to the `main` pane and collapses the rest.
+fn panes<const N: usize>(
+    area: Rect,
+    direction: Direction,
+    constraints: [Constraint; N],
+    main: usize,
+    zoomed: bool,
+) -> Rc<[Rect]> {
+    let constraints = if zoomed {
+        std::array::from_fn(|i| {
+            if i == main {
+                Constraint::Min(0)
+            } else {
+                Constraint::Length(0)
+            }
+        })
+    } else {
+        constraints
+    };
+    Layout::default()
+        .direction(direction)
+        .constraints(constraints)
+        .split(area)
+}
+
+fn draw_too_small(f: &mut Frame, area: Rect) {
+    let lines = vec![
+        Line::styled(tr!("too-small"), Style::default().fg(Color::Yellow)),
+        Line::from(format!("{}x{}", area.width, area.height)),
+        Line::from(tr!(
+            "too-small-need",
+            size = format!("{}x{}", MIN_WIDTH, MIN_HEIGHT)
+        )),
+    ];
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let message = Paragraph::new(lines).alignment(Alignment::Center);
+    f.render_widget(
+        message,
+        Rect {
+            y: area.y + top,
+            height: area.height - top,
+            ..area
+        },
+    );
+}
+
+fn draw_debug_log(f: &mut Frame, area: Rect) {
+    let lines: Vec<Line> = logging::recent(area.height.saturating_sub(2) as usize)
+        .into_iter()
+        .map(Line::from)
+        .collect();
+    let log = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .title(tr!("debug-log-title", path = logging::log_path().display())),
+    );
+    f.render_widget(log, area);
+}
+
+/// Stacks toasts upward from the bottom-right corner, oldest lowest.
+fn draw_toasts(f: &mut Frame, toasts: &Toasts) {
+    let area = f.area();
+    let width = area.width.min(50);
+    let mut bottom = area.bottom();
+    for toast in toasts.visible() {
+        let lines = (toast.message.chars().count() as u16).div_ceil(width.saturating_sub(2).max(1));
+        let height = lines + 2;
+        if bottom < area.y + height {
+            break;
+        }
+        bottom -= height;
+        let rect = Rect::new(area.right() - width, bottom, width, height);
+        let (title, color) = match toast.level {
+            Level::Info => (tr!("toast-info"), Color::Green),
+            Level::Error => (tr!("toast-error"), Color::Red),
+        };
+        let toast = Paragraph::new(toast.message.as_str())
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color))
+                    .title(title),
+            );
+        f.render_widget(Clear, rect);
+        f.render_widget(toast, rect);
+    }
+}
+
+fn draw_interfaces(f: &mut Frame, picker: &InterfacePicker) {
+    let area = centered_rect(f.area(), 60, 14);
+    let items: Vec<ListItem> = picker
+        .addresses
+        .iter()
+        .map(|a| {
+            ListItem::new(format!(
+                "{:<16} {:<20} {}",
+                a.name,
+                format!("{}/{}", a.ip, a.prefix),
+                a.netmask
+            ))
+        })
+        .collect();
+    let title = match &picker.error {
+        Some(error) => error.clone(),
+        None if picker.addresses.is_empty() => tr!("interfaces-none"),
+        None => tr!("interfaces-title"),
+    };
+    let list = List::new(items)
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(title));
+    let mut state = ListState::default().with_selected(Some(picker.selected));
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_export(f: &mut Frame, picker: &ExportPicker) {
+    let area = centered_rect(f.area(), 80, 16);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(28), Constraint::Min(0)])
+        .split(area);
+    let items: Vec<ListItem> = export::FORMATS
+        .iter()
+        .map(|format| ListItem::new(format.name()))
+        .collect();
+    let list = List::new(items)
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("export-formats")),
+        );
+    let mut state = ListState::default().with_selected(Some(picker.selected));
+    let preview = Paragraph::new(picker.preview()).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(tr!("export-preview")),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, chunks[0], &mut state);
+    f.render_widget(preview, chunks[1]);
+}
+
+fn draw_palette(f: &mut Frame, palette: &Palette, keys: &Keymap, context: Context) {
+    let area = centered_rect(f.area(), 70, 20);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let query_box = Paragraph::new(palette.query.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("palette-title")),
+        );
+    let items: Vec<ListItem> = palette
+        .matches()
+        .into_iter()
+        .map(|(action, name, description)| {
+            let key = keys
+                .key_for(context, action)
+                .map(|k| k.to_string())
+                .unwrap_or_default();
+            ListItem::new(format!("{:<18} {:<45} {}", name, description, key))
+        })
+        .collect();
+    let list = List::new(items)
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("palette-actions")),
+        );
+    let mut state = ListState::default().with_selected(Some(palette.selected));
+
+    f.render_widget(Clear, area);
+    f.render_widget(query_box, chunks[0]);
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
+
+fn draw_session_prompt(f: &mut Frame, prompt: &SessionPrompt) {
+    let area = centered_rect(f.area(), 60, 14);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let title = match prompt.kind {
+        PromptKind::Save => tr!("session-save-title"),
+        PromptKind::Load => tr!("session-load-title"),
+    };
+    let name_box = Paragraph::new(prompt.name.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(title));
+    let items: Vec<ListItem> = prompt.matches().into_iter().map(ListItem::new).collect();
+    let list = List::new(items)
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("session-list", path = session::session_dir().display())),
+        );
+    let mut state = ListState::default().with_selected(Some(prompt.selected));
+
+    f.render_widget(Clear, area);
+    f.render_widget(name_box, chunks[0]);
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
+
+/// A rectangle of `percent_x` width and `height` rows centered in `area`.
+fn centered_rect(area: Rect, percent_x: u16, height: u16) -> Rect {
+    let width = area.width * percent_x / 100;
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Where each calculator panel goes at the current terminal size. Panels
+/// without room are left out.
+struct CalculatorLayout {
+    input: Rect,
+    results: Rect,
+    binary: Rect,
+    warnings: Option<Rect>,
+    history: Option<Rect>,
+    help: Rect,
+}
+
+impl CalculatorLayout {
+    /// Zoomed, the results take the whole area. Wide terminals put the
+    /// binary view and history beside the results; narrower ones stack
+    /// everything, dropping history below `NARROW_WIDTH` columns and on
+    /// short terminals.
+    fn new(area: Rect, warnings: bool, zoomed: bool) -> Self {
+        if zoomed {
+            return Self {
+                input: Rect::default(),
+                results: area,
+                binary: Rect::default(),
+                warnings: None,
+                history: None,
+                help: Rect::default(),
+            };
+        }
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(6),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(area);
+        let (input, main, help) = (rows[0], rows[1], rows[2]);
+
+        if area.width >= WIDE_WIDTH {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(0), Constraint::Length(SIDE_COLUMN_WIDTH)])
+                .split(main);
+            let side = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(6),
+                    Constraint::Percentage(if warnings { 50 } else { 0 }),
+                    Constraint::Min(0),
+                ])
+                .split(columns[1]);
+            return Self {
+                input,
+                results: columns[0],
+                binary: side[0],
+                warnings: warnings.then_some(side[1]),
+                history: Some(side[2]),
+                help,
+            };
+        }
+
+        let roomy = area.height >= 30;
+        let bottom = if roomy || warnings {
+            Constraint::Percentage(25)
+        } else {
+            Constraint::Length(0)
+        };
+        let stacked = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(6), bottom])
+            .split(main);
+        let (warnings, history) = if !roomy || area.width < NARROW_WIDTH {
+            (warnings.then_some(stacked[2]), None)
+        } else {
+            let panes = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .split(stacked[2]);
+            (warnings.then_some(panes[0]), Some(panes[1]))
+        };
+        Self {
+            input,
+            results: stacked[0],
+            binary: stacked[1],
+            warnings,
+            history,
+            help,
+        }
+    }
+}
+
+fn draw_calculator(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap, geoip: &GeoIp) {
+    let layout = CalculatorLayout::new(area, !ws.warnings.is_empty(), ws.zoomed);
+
+    let key = |action| keys.hint(Context::Calculator, action);
+    let input_title = match ws.input_mode {
+        InputMode::IP => tr!("calc-enter-ip"),
+        InputMode::Subnet => tr!("calc-enter-mask"),
+        InputMode::Hosts => tr!("calc-enter-hosts"),
+        InputMode::Subnets => tr!("calc-enter-subnets"),
+        InputMode::NoTyping => tr!(
+            "calc-input-help",
+            ip = key(Action::FocusIp),
+            mask = key(Action::FocusMask),
+            hosts = key(Action::FocusHosts),
+            subnets = key(Action::FocusSubnets)
+        ),
+    };
+
+    let input_text = vec![
+        quad_line(
+            tr!("field-ip"),
+            &ws.ip_input,
+            ws.octet.filter(|_| matches!(ws.input_mode, InputMode::IP)),
+        ),
+        quad_line(
+            tr!("field-subnet"),
+            &ws.subnet_input,
+            ws.octet
+                .filter(|_| matches!(ws.input_mode, InputMode::Subnet)),
+        ),
+        Line::from(format!("{}: {}", tr!("field-hosts-needed"), ws.hosts_input)),
+        Line::from(format!(
+            "{}: {}",
+            tr!("field-subnets-needed"),
+            ws.subnets_input
+        )),
+    ];
+    let input_box = Paragraph::new(input_text)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(input_title));
+
+    let class_text = ws
+        .ip_class
+        .map_or_else(|| "-".to_string(), |c| c.to_string());
+    let scope_text = match ws.scope {
+        Some((scope, Some(rfc))) => format!("{} ({})", scope, rfc),
+        Some((scope, None)) => scope.to_string(),
+        None => "-".to_string(),
+    };
+    let zones_text = match ws.reverse_zones.len() {
+        0 => "-".to_string(),
+        n if n <= 4 => ws.reverse_zones.join(", "),
+        n => tr!(
+            "calc-zones-more",
+            zones = ws.reverse_zones[..4].join(", "),
+            count = n
+        ),
+    };
+    let sizing_text = match ws.hosts_prefix {
+        Some(prefix) => tr!(
+            "calc-sizing",
+            prefix = prefix,
+            mask = Ipv4Addr::from(cidr::prefix_mask(prefix)),
+            usable = cidr::group_digits(cidr::usable_hosts(prefix)),
+            key = key(Action::UseHostsPrefix)
+        ),
+        None => "-".to_string(),
+    };
+    let subnetting_text = match &ws.subnetting {
+        Some(plan) => tr!(
+            "calc-subnetting",
+            bits = plan.borrowed_bits,
+            prefix = plan.new_prefix,
+            mask = Ipv4Addr::from(cidr::prefix_mask(plan.new_prefix)),
+            subnets = cidr::group_digits(plan.subnets),
+            hosts = cidr::group_digits(plan.hosts_per_subnet)
+        ),
+        None => "-".to_string(),
+    };
+    let dhcp_text = match &ws.dhcp {
+        Some(scope) => {
+            let excluded = match scope.excluded {
+                Some((first, last)) => tr!("calc-dhcp-static", first = first, last = last),
+                None => String::new(),
+            };
+            tr!(
+                "calc-dhcp",
+                gateway = scope.gateway,
+                excluded = excluded,
+                first = scope.pool.0,
+                last = scope.pool.1,
+                percent = scope.static_percent,
+                shrink = key(Action::ShrinkStaticRange),
+                grow = key(Action::GrowStaticRange)
+            )
+        }
+        None => "-".to_string(),
+    };
+    let cloud = ws.cloud.zip(ws.network_address.zip(ws.prefix_length));
+    let host_count_text = match cloud {
+        Some((cloud, (network, prefix))) => tr!(
+            "calc-cloud-hosts",
+            hosts = cidr::group_digits(cloud.usable_hosts(network, prefix)),
+            cloud = cloud,
+            reserved = cloud.reserved(network, prefix).len()
+        ),
+        None => cidr::group_digits(ws.host_count.unwrap_or(0)),
+    };
+    let cloud_text = match cloud {
+        Some((cloud, (network, prefix))) => {
+            let reserved: Vec<String> = cloud
+                .reserved(network, prefix)
+                .iter()
+                .map(|r| format!("{} {}", r.address, r.purpose))
+                .collect();
+            format!("{}: {}", cloud, reserved.join(", "))
+        }
+        None => tr!("calc-cloud-off", key = key(Action::CycleCloud)),
+    };
+    let mut results = vec![
+        (
+            tr!("result-network"),
+            ws.network_address
+                .unwrap_or(Ipv4Addr::new(0, 0, 0, 0))
+                .to_string(),
+        ),
+        (
+            tr!("result-broadcast"),
+            ws.broadcast_address
+                .unwrap_or(Ipv4Addr::new(0, 0, 0, 0))
+                .to_string(),
+        ),
+        (
+            tr!("result-subnet-count"),
+            cidr::group_digits(ws.subnet_count.unwrap_or(0)),
+        ),
+        (tr!("result-host-count"), host_count_text),
+        (tr!("result-cloud"), cloud_text),
+        (tr!("result-class"), class_text),
+        (tr!("result-scope"), scope_text),
+        (tr!("result-reverse-zone"), zones_text),
+        (tr!("result-hosts-prefix"), sizing_text),
+        (tr!("result-subnetting"), subnetting_text),
+        (tr!("result-dhcp"), dhcp_text),
+    ];
+    // Without a configured database the line is left out entirely.
+    if geoip.is_loaded() {
+        let location = ws
+            .ip_address
+            .and_then(|ip| geoip.lookup(ip))
+            .map_or_else(|| "-".to_string(), |record| record.to_string());
+        results.push((tr!("result-location"), location));
+    }
+    let result_text: Vec<String> = results
+        .into_iter()
+        .map(|(label, value)| format!("{}: {}", label, value))
+        .collect();
+    let result_box = Paragraph::new(result_text.join("\n"))
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("calc-results")),
+        );
+
+    let history: Vec<ListItem> = ws
+        .history
+        .iter()
+        .map(|(ip, subnet)| ListItem::new(format!("{} {}", ip, subnet)))
+        .collect();
+    let history_box = List::new(history).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(tr!("calc-history")),
+    );
+    let help: Vec<String> = [
+        (Action::OpenMembership, tr!("help-membership")),
+        (Action::OpenOverlap, tr!("help-overlaps")),
+        (Action::OpenTree, tr!("help-tree")),
+        (Action::OpenRange, tr!("help-range")),
+        (Action::OpenHosts, tr!("help-hosts")),
+        (Action::OpenQuiz, tr!("help-quiz")),
+        (Action::OpenIpv6, tr!("help-ipv6")),
+        (Action::OpenArithmetic, tr!("help-arithmetic")),
+        (Action::OpenCleanup, tr!("help-cleanup")),
+        (Action::PickInterface, tr!("help-interfaces")),
+        (Action::Export, tr!("help-export")),
+        (Action::ExportReport, tr!("help-report")),
+        (Action::ToggleCheatSheet, tr!("help-cheat-sheet")),
+        (Action::ToggleTransition, tr!("help-transition")),
+        (Action::LookupRdap, tr!("help-whois")),
+        (Action::PinCompare, tr!("help-compare")),
+        (Action::Explain, tr!("help-explain")),
+        (Action::ToggleZoom, tr!("help-zoom")),
+        (Action::SwapIpMask, tr!("help-swap")),
+        (Action::ClearField, tr!("help-clear-field")),
+        (Action::ResetWorkspace, tr!("help-reset")),
+        (Action::OpenSweep, tr!("help-sweep")),
+        (Action::NextSubnet, tr!("help-next-subnet")),
+        (Action::PrevSubnet, tr!("help-prev-subnet")),
+        (Action::NextWorkspace, tr!("help-next-workspace")),
+        (Action::NewWorkspace, tr!("help-new-workspace")),
+        (Action::CloseWorkspace, tr!("help-close-workspace")),
+        (Action::SaveSession, tr!("help-save")),
+        (Action::LoadSession, tr!("help-load")),
+        (Action::Undo, tr!("help-undo")),
+        (Action::Redo, tr!("help-redo")),
+        (Action::CommandPalette, tr!("help-palette")),
+        (Action::Quit, tr!("help-quit")),
+    ]
+    .into_iter()
+    .map(|(action, label)| format!("{}: {}", key(action), label))
+    .collect();
+    let help = Paragraph::new(help.join("  ")).style(Style::default().fg(Color::DarkGray));
+
+    // A side pane sits beside the results when both fit and covers them
+    // otherwise.
+    let results = match ws.side_pane {
+        Some(pane) => {
+            let width = match pane {
+                SidePane::CheatSheet => 68,
+                SidePane::Transition => 52,
+                SidePane::Rdap => 52,
+                SidePane::Compare => 62,
+            };
+            let (pane_area, results) = if layout.results.width >= width + MIN_RESULTS_WIDTH {
+                let split = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(0), Constraint::Length(width)])
+                    .split(layout.results);
+                (split[1], Some(split[0]))
+            } else {
+                (layout.results, None)
+            };
+            match pane {
+                SidePane::CheatSheet => draw_cheat_sheet(f, pane_area, ws, keys),
+                SidePane::Transition => draw_transition(f, pane_area, ws, keys),
+                SidePane::Rdap => draw_rdap(f, pane_area, ws, keys),
+                SidePane::Compare => draw_compare(f, pane_area, ws, keys),
+            }
+            results
+        }
+        None => Some(layout.results),
+    };
+
+    f.render_widget(input_box, layout.input);
+    if let Some(results) = results {
+        f.render_widget(result_box, results);
+    }
+    f.render_widget(binary_view(ws, keys), layout.binary);
+    f.render_widget(help, layout.help);
+    if let Some(history) = layout.history {
+        f.render_widget(history_box, history);
+    }
+    if let Some(warnings) = layout.warnings {
+        let warning_box = Paragraph::new(ws.warnings.join("\n"))
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(Color::Red))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(tr!("calc-warnings")),
+            );
+        f.render_widget(warning_box, warnings);
+    }
+
+    draw_suggestions(f, area, layout.input, ws);
+}
+
+/// The calculated address in each IPv6 transition form.
+fn draw_transition(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let lines: Vec<Line> = match ws.ip_address {
+        Some(ip) => ipv6::transition_forms(ip)
+            .into_iter()
+            .flat_map(|(label, address)| {
+                [
+                    Line::styled(label, Style::default().fg(Color::Cyan)),
+                    Line::from(format!("  {}", address)),
+                ]
+            })
+            .collect(),
+        None => vec![Line::from(tr!("transition-empty"))],
+    };
+    let title = tr!(
+        "transition-title",
+        key = keys.hint(Context::Calculator, Action::ToggleTransition)
+    );
+    let pane = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(pane, area);
+}
+
+/// The registry's record for the looked-up address.
+fn draw_rdap(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let Some(lookup) = &ws.rdap else {
+        return;
+    };
+    let label = |text| Span::styled(text, Style::default().fg(Color::Cyan));
+    let field = |name: String, value: &Option<String>| {
+        Line::from(vec![
+            label(format!("{}: ", name)),
+            Span::raw(value.as_deref().unwrap_or("-").to_string()),
+        ])
+    };
+    let lines = match &lookup.result {
+        None => vec![Line::from(tr!("rdap-waiting"))],
+        Some(Err(err)) => vec![Line::styled(err.clone(), Style::default().fg(Color::Red))],
+        Some(Ok(record)) => {
+            let asns: Vec<String> = record.asns.iter().map(|asn| format!("AS{}", asn)).collect();
+            let mut lines = vec![
+                field(tr!("rdap-org"), &record.org),
+                field(tr!("rdap-network"), &record.name),
+                field(tr!("rdap-handle"), &record.handle),
+                field(tr!("rdap-country"), &record.country),
+                field(
+                    tr!("rdap-asn"),
+                    &(!asns.is_empty()).then(|| asns.join(", ")),
+                ),
+                Line::from(label(format!("{}:", tr!("rdap-allocated")))),
+            ];
+            lines.extend(
+                record
+                    .prefixes
+                    .iter()
+                    .map(|p| Line::from(format!("  {}", p))),
+            );
+            lines
+        }
+    };
+    let title = tr!(
+        "rdap-title",
+        ip = lookup.ip,
+        key = keys.hint(Context::Calculator, Action::LookupRdap)
+    );
+    let pane = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(pane, area);
+}
+
+/// The pinned block's results beside the current ones, differing values
+/// highlighted.
+fn draw_compare(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let Some(pinned) = ws.pinned else {
+        return;
+    };
+    let current = ws.block();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(4)])
+        .split(area);
+
+    let rows = compare::fields(pinned, current).into_iter().map(|field| {
+        let style = if field.differs() && current.is_some() {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        Row::new(vec![
+            Span::styled(field.name, Style::default().fg(Color::Cyan)),
+            Span::raw(field.pinned),
+            Span::raw(field.current),
+        ])
+        .style(style)
+    });
+    let title = tr!(
+        "compare-title",
+        key = keys.hint(Context::Calculator, Action::PinCompare)
+    );
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(17),
+            Constraint::Min(0),
+            Constraint::Min(0),
+        ],
+    )
+    .header(Row::new(vec![
+        String::new(),
+        tr!("compare-pinned"),
+        tr!("compare-current"),
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(title));
+
+    let relation = match current {
+        Some(current) => compare::relation(pinned, current),
+        None => tr!("compare-empty"),
+    };
+    let relation_box = Paragraph::new(relation).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(tr!("compare-overlap")),
+    );
+
+    f.render_widget(table, chunks[0]);
+    f.render_widget(relation_box, chunks[1]);
+}
+
+/// Every prefix from /0 to /32, with the current one highlighted.
+fn draw_cheat_sheet(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let visible = area.height.saturating_sub(3) as usize;
+    let offset = ws.cheat_sheet_offset().min(33usize.saturating_sub(visible));
+    let rows: Vec<Row> = (offset as u32..=32)
+        .take(visible)
+        .map(|prefix| {
+            let mask = cidr::prefix_mask(prefix);
+            let row = Row::new(vec![
+                format!("/{}", prefix),
+                Ipv4Addr::from(mask).to_string(),
+                Ipv4Addr::from(!mask).to_string(),
+                cidr::group_digits(cidr::usable_hosts(prefix)),
+                cidr::group_digits(cidr::block_size(prefix)),
+            ]);
+            if ws.prefix_length == Some(prefix) {
+                row.style(Style::default().fg(Color::Black).bg(Color::Yellow))
+            } else {
+                row
+            }
+        })
+        .collect();
+    let title = tr!(
+        "cheat-sheet-title",
+        up = keys.hint(Context::Calculator, Action::Up),
+        down = keys.hint(Context::Calculator, Action::Down),
+        key = keys.hint(Context::Calculator, Action::ToggleCheatSheet)
+    );
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(4),
+            Constraint::Length(15),
+            Constraint::Length(15),
+            Constraint::Length(13),
+            Constraint::Length(13),
+        ],
+    )
+    .header(Row::new(vec![
+        String::new(),
+        tr!("column-mask"),
+        tr!("column-wildcard"),
+        tr!("column-hosts"),
+        tr!("column-block"),
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(table, area);
+}
+
+/// The completion dropdown, anchored below the line being typed on.
+fn draw_suggestions(f: &mut Frame, area: Rect, input_area: Rect, ws: &Workspace) {
+    let suggestions = ws.suggestions();
+    let (row, column) = match ws.input_mode {
+        InputMode::IP => (1, label_width(tr!("field-ip")) + ws.ip_input.len()),
+        InputMode::Subnet => (2, label_width(tr!("field-subnet")) + ws.subnet_input.len()),
+        _ => return,
+    };
+    if suggestions.is_empty() {
+        return;
+    }
+    let x = (input_area.x + 1 + column as u16).min(area.right().saturating_sub(34));
+    let y = input_area.y + row + 1;
+    let height = (suggestions.len().min(6) as u16 + 2).min(area.bottom().saturating_sub(y));
+    let popup = Rect::new(x, y, 34.min(area.width), height);
+    let items: Vec<ListItem> = suggestions
+        .iter()
+        .map(|s| ListItem::new(format!("{:<16} {}", s.value, s.detail)))
+        .collect();
+    let list = List::new(items)
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("suggestions-title")),
+        );
+    let mut state = ListState::default().with_selected(Some(ws.suggestion));
+
+    f.render_widget(Clear, popup);
+    f.render_stateful_widget(list, popup, &mut state);
+}
+
+/// Columns taken by `label` and the `: ` after it in a field line.
+fn label_width(label: String) -> usize {
+    Span::raw(format!("{}: ", label)).width()
+}
+
+/// A labelled dotted quad with the octet under `cursor` highlighted.
+fn quad_line(label: String, text: &str, cursor: Option<OctetCursor>) -> Line<'static> {
+    let Some(cursor) = cursor else {
+        return Line::from(format!("{}: {}", label, text));
+    };
+    let mut spans = vec![Span::raw(format!("{}: ", label))];
+    for (i, octet) in text.split('.').enumerate() {
+        if i > 0 {
+            spans.push(Span::raw("."));
+        }
+        if i == cursor.index {
+            let octet = if octet.is_empty() { "_" } else { octet };
+            spans.push(Span::styled(
+                octet.to_string(),
+                Style::default().fg(Color::Black).bg(Color::Yellow),
+            ));
+        } else {
+            spans.push(Span::raw(octet.to_string()));
+        }
+    }
+    Line::from(spans)
+}
+
+fn binary_view(ws: &Workspace, keys: &Keymap) -> Paragraph<'static> {
+    let (Some(ip), Some(subnet), Some(network), Some(broadcast)) = (
+        ws.ip_address,
+        ws.subnet_mask,
+        ws.network_address,
+        ws.broadcast_address,
+    ) else {
+        return Paragraph::new("").block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("binary-title-empty")),
+        );
+    };
+    let prefix = ws
+        .prefix_length
+        .unwrap_or_else(|| u32::from(subnet).leading_ones());
+    let lines = vec![
+        binary_line(tr!("binary-ip"), ip, prefix),
+        binary_line(tr!("binary-mask"), subnet, prefix),
+        binary_line(tr!("binary-network"), network, prefix),
+        binary_line(tr!("binary-broadcast"), broadcast, prefix),
+    ];
+    Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(tr!(
+        "binary-title",
+        prefix = prefix,
+        shrink = keys.hint(Context::Calculator, Action::ShrinkPrefix),
+        grow = keys.hint(Context::Calculator, Action::GrowPrefix)
+    )))
+}
+
+/// Renders an address bit by bit, network bits and host bits in different
+/// colors.
+fn binary_line(label: String, addr: Ipv4Addr, prefix: u32) -> Line<'static> {
+    let mut spans = vec![Span::raw(format!("{:<10}", label))];
+    spans.extend(bit_spans(addr, prefix, prefix));
+    Line::from(spans)
+}
+
+/// The bits of an address in dotted octets. Bits from `borrowed_from` up
+/// to `prefix`, borrowed from the host part for subnets, stand out from
+/// the network and host bits.
+fn bit_spans(addr: Ipv4Addr, borrowed_from: u32, prefix: u32) -> Vec<Span<'static>> {
+    let bits = u32::from(addr);
+    let mut spans = Vec::with_capacity(35);
+    for i in 0..32 {
+        if i > 0 && i % 8 == 0 {
+            spans.push(Span::raw("."));
+        }
+        let bit = if bits & (1 << (31 - i)) != 0 {
+            "1"
+        } else {
+            "0"
+        };
+        let color = if i < borrowed_from {
+            Color::Cyan
+        } else if i < prefix {
+            Color::Yellow
+        } else {
+            Color::Green
+        };
+        spans.push(Span::styled(bit, Style::default().fg(color)));
+    }
+    spans
+}
+
+fn draw_membership(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let chunks = panes(
+        area,
+        Direction::Vertical,
+        [
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+        ],
+        1,
+        ws.zoomed,
+    );
+
+    let check = &ws.membership;
+    let key = |action| keys.hint(Context::Membership, action);
+    let input_title = match check.input_mode {
+        MembershipInput::Ip => tr!("membership-enter-ip"),
+        MembershipInput::Cidr => tr!("membership-enter-cidr"),
+        MembershipInput::NoTyping => tr!(
+            "membership-help",
+            ip = key(Action::FocusIp),
+            cidr = key(Action::FocusCidr),
+            back = key(Action::Back)
+        ),
+    };
+    let input_text = vec![
+        quad_line(
+            tr!("field-ip"),
+            &check.ip_input,
+            ws.octet
+                .filter(|_| matches!(check.input_mode, MembershipInput::Ip)),
+        ),
+        Line::from(format!("{}: {}", tr!("field-cidr"), check.cidr_input)),
+    ];
+    let input_box = Paragraph::new(input_text)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(input_title));
+
+    let (result_text, color) = match &check.result {
+        None => (String::new(), Color::Reset),
+        Some(Err(message)) => (message.to_string(), Color::Red),
+        Some(Ok((ip, Membership::Outside { network, prefix }))) => (
+            tr!(
+                "membership-outside",
+                ip = ip,
+                block = format!("{}/{}", network, prefix)
+            ),
+            Color::Red,
+        ),
+        Some(Ok((
+            ip,
+            Membership::Inside {
+                network,
+                prefix,
+                offset,
+            },
+        ))) => {
+            let size = cidr::block_size(*prefix);
+            let position = if *prefix >= 31 {
+                tr!("membership-host", number = offset + 1)
+            } else if *offset == 0 {
+                tr!("membership-network")
+            } else if u64::from(*offset) == size - 1 {
+                tr!("membership-broadcast")
+            } else {
+                tr!("membership-host", number = offset)
+            };
+            (
+                tr!(
+                    "membership-inside",
+                    ip = ip,
+                    block = format!("{}/{}", network, prefix),
+                    offset = cidr::group_digits(u64::from(*offset)),
+                    size = cidr::group_digits(size),
+                    position = position
+                ),
+                Color::Green,
+            )
+        }
+    };
+    let result_box = Paragraph::new(result_text)
+        .style(Style::default().fg(color))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("membership-title")),
+        );
+
+    f.render_widget(input_box, chunks[0]);
+    f.render_widget(result_box, chunks[1]);
+}
+
+fn draw_overlap(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let chunks = panes(
+        area,
+        Direction::Vertical,
+        [
+            Constraint::Length(3),
+            Constraint::Percentage(35),
+            Constraint::Min(0),
+        ],
+        2,
+        ws.zoomed,
+    );
+
+    let check = &ws.overlap;
+    let path_box = Paragraph::new(check.path_input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(match &check.error {
+                    Some(error) => error.clone(),
+                    None => tr!("overlap-load-file"),
+                }),
+        );
+
+    let key = |action| keys.hint(Context::Overlap, action);
+    let list_title = match check.input_mode {
+        OverlapInput::List => tr!("overlap-enter-list"),
+        OverlapInput::Path => tr!("overlap-enter-path"),
+        OverlapInput::NoTyping => tr!(
+            "overlap-help",
+            edit = key(Action::EditList),
+            load = key(Action::LoadFile),
+            back = key(Action::Back)
+        ),
+    };
+    let list_box = Paragraph::new(check.list_input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(list_title));
+
+    let filter = &check.filter;
+    let rows = check
+        .overlaps
+        .iter()
+        .map(|o| {
+            let (relation, color) = match o.relation {
+                Relation::Identical => (tr!("overlap-duplicate"), Color::Red),
+                Relation::Contains => (tr!("overlap-contains"), Color::Yellow),
+            };
+            ([o.outer.to_string(), relation, o.inner.to_string()], color)
+        })
+        .filter(|(cells, _)| filter.keeps(&cells.join(" ")))
+        .skip(check.scroll)
+        .map(|(cells, color)| {
+            Row::new(cells.map(|cell| highlight(cell, filter.query())))
+                .style(Style::default().fg(color))
+        });
+    let mut title = tr!(
+        "overlap-title",
+        blocks = check.blocks.len(),
+        pairs = check.overlaps.len(),
+        up = key(Action::Up),
+        down = key(Action::Down)
+    );
+    title.push_str(&filter_title(filter, key(Action::Search)));
+    if !check.invalid.is_empty() {
+        title.push_str(&ignored(&check.invalid));
+    }
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(20),
+            Constraint::Length(12),
+            Constraint::Length(20),
+        ],
+    )
+    .header(Row::new(vec![
+        tr!("column-block"),
+        tr!("column-relation"),
+        tr!("column-overlaps"),
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(path_box, chunks[0]);
+    f.render_widget(list_box, chunks[1]);
+    f.render_widget(table, chunks[2]);
+}
+
+fn draw_tree(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let chunks = panes(
+        area,
+        Direction::Vertical,
+        [
+            Constraint::Length(3),
+            Constraint::Percentage(30),
+            Constraint::Min(0),
+            Constraint::Length(6),
+        ],
+        2,
+        ws.zoomed,
+    );
+
+    let view = &ws.tree;
+    let key = |action| keys.hint(Context::Tree, action);
+    let input_title = match view.input_mode {
+        TreeInput::Parent => tr!("tree-enter-parent"),
+        TreeInput::Children => tr!("tree-enter-children"),
+        TreeInput::NoTyping => tr!(
+            "tree-help",
+            parent = key(Action::FocusParent),
+            edit = key(Action::EditList),
+            collapse = key(Action::Toggle),
+            back = key(Action::Back)
+        ),
+    };
+    let parent_box = Paragraph::new(view.parent_input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(input_title));
+    let children_box = Paragraph::new(view.children_input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("tree-children")),
+        );
+
+    let items: Vec<ListItem> = view
+        .rows()
+        .iter()
+        .map(|row| {
+            let marker = match (row.has_children, row.collapsed) {
+                (false, _) => "•",
+                (true, false) => "▾",
+                (true, true) => "▸",
+            };
+            ListItem::new(format!(
+                "{}{} {:<20} {}",
+                "  ".repeat(row.depth),
+                marker,
+                row.block.to_string(),
+                tr!(
+                    "tree-free",
+                    free = cidr::group_digits(row.free),
+                    size = cidr::group_digits(row.block.size()),
+                    percent = format!("{:.1}", row.free as f64 * 100.0 / row.block.size() as f64)
+                )
+            ))
+        })
+        .collect();
+    let mut title = match &view.error {
+        Some(error) => error.clone(),
+        None => tr!("tree-title"),
+    };
+    if !view.outside.is_empty() {
+        let outside: Vec<String> = view.outside.iter().map(|b| b.to_string()).collect();
+        title.push_str(&tr!("tree-outside", blocks = outside.join(", ")));
+    }
+    if !view.invalid.is_empty() {
+        title.push_str(&ignored(&view.invalid));
+    }
+    let list = List::new(items)
+        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(title));
+    let mut state = ListState::default().with_selected(Some(view.selected));
+
+    f.render_widget(parent_box, chunks[0]);
+    f.render_widget(children_box, chunks[1]);
+    f.render_stateful_widget(list, chunks[2], &mut state);
+    if let Some(node) = view.selected_node() {
+        draw_usage(f, chunks[3], node);
+    }
+}
+
+/// Used against free space across the selected block, one cell per equal
+/// slice, followed by the gaps left to allocate.
+fn draw_usage(f: &mut Frame, area: Rect, node: &TreeNode) {
+    let block = Block::default().borders(Borders::ALL).title(tr!(
+        "usage-title",
+        block = node.block,
+        percent = format!(
+            "{:.1}",
+            100.0 - node.free() as f64 * 100.0 / node.block.size() as f64
+        )
+    ));
+    let inner = block.inner(area);
+    let rows = inner.height.saturating_sub(1);
+    let usage = node.usage(usize::from(inner.width) * usize::from(rows));
+    let mut lines: Vec<Line> = usage
+        .chunks(usize::from(inner.width).max(1))
+        .map(|row| {
+            let spans: Vec<Span> = row
+                .iter()
+                .map(|&used| match used {
+                    u if u >= 0.999 => Span::styled("█", Style::default().fg(Color::Green)),
+                    u if u > 0.001 => Span::styled("▒", Style::default().fg(Color::Yellow)),
+                    _ => Span::styled("·", Style::default().fg(Color::DarkGray)),
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+    let gaps: Vec<String> = node.gaps().iter().map(|gap| gap.to_string()).collect();
+    lines.push(Line::from(if gaps.is_empty() {
+        tr!("usage-no-gaps")
+    } else {
+        tr!("usage-gaps", gaps = gaps.join(", "))
+    }));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_range(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let chunks = panes(
+        area,
+        Direction::Vertical,
+        [Constraint::Length(3), Constraint::Min(0)],
+        1,
+        ws.zoomed,
+    );
+
+    let converter = &ws.range;
+    let key = |action| keys.hint(Context::Range, action);
+    let input_title = if converter.typing {
+        tr!("range-enter")
+    } else {
+        tr!(
+            "range-help",
+            edit = key(Action::EditRange),
+            back = key(Action::Back)
+        )
+    };
+    let input_box = Paragraph::new(converter.input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(input_title));
+
+    let title = match &converter.error {
+        Some(error) => error.clone(),
+        None => {
+            let covered = converter.blocks.iter().map(|b| b.size()).sum::<u64>();
+            tr!(
+                "range-title",
+                blocks = converter.blocks.len(),
+                addresses = cidr::group_digits(covered),
+                up = key(Action::Up),
+                down = key(Action::Down)
+            ) + &filter_title(&converter.filter, key(Action::Search))
+        }
+    };
+    let filter = &converter.filter;
+    let items: Vec<ListItem> = converter
+        .blocks
+        .iter()
+        .map(|b| b.to_string())
+        .filter(|text| filter.keeps(text))
+        .skip(converter.scroll)
+        .map(|text| ListItem::new(highlight(text, filter.query())))
+        .collect();
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(input_box, chunks[0]);
+    f.render_widget(list, chunks[1]);
+}
+
+fn draw_ipv6(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let chunks = panes(
+        area,
+        Direction::Vertical,
+        [
+            Constraint::Length(3),
+            Constraint::Length(6),
+            Constraint::Length(4),
+            Constraint::Min(0),
+        ],
+        1,
+        ws.zoomed,
+    );
+
+    let tool = &ws.ipv6;
+    let key = |action| keys.hint(Context::Ipv6, action);
+    let input_title = match tool.input_mode {
+        Ipv6Input::Address => tr!("ipv6-enter-address"),
+        _ => tr!(
+            "ipv6-help",
+            edit = key(Action::FocusIp),
+            back = key(Action::Back)
+        ),
+    };
+    let input_box = Paragraph::new(tool.input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(input_title));
+
+    let field = |label: String, value: String| format!("{:<12}{}", label + ":", value);
+    let lines = match &tool.result {
+        Some(Ok(forms)) => {
+            let mut lines = vec![
+                Line::from(field(tr!("ipv6-compressed"), forms.compressed())),
+                Line::from(field(tr!("ipv6-expanded"), forms.expanded())),
+            ];
+            if let Some(network) = forms.network() {
+                lines.push(Line::from(field(tr!("ipv6-network"), network)));
+            }
+            if let Some((kind, ip)) = ipv6::embedded_ipv4(forms.address) {
+                lines.push(Line::from(field(
+                    tr!("ipv6-embedded"),
+                    format!("{} ({})", ip, kind),
+                )));
+            }
+            match &forms.zone {
+                Some(zone) if !forms.is_link_local() => lines.push(Line::styled(
+                    field(tr!("ipv6-zone"), tr!("ipv6-zone-unused", zone = zone)),
+                    Style::default().fg(Color::Red),
+                )),
+                Some(zone) => lines.push(Line::from(field(tr!("ipv6-zone"), zone.clone()))),
+                None => {}
+            }
+            lines
+        }
+        Some(Err(err)) => vec![Line::styled(err.clone(), Style::default().fg(Color::Red))],
+        None => Vec::new(),
+    };
+    let result_title = match &tool.note {
+        Some(note) => tr!("ipv6-title-note", note = note),
+        None => tr!("ipv6-title", key = key(Action::GenerateUla)),
+    };
+    let result_box =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(result_title));
+
+    let eui_title = match tool.input_mode {
+        Ipv6Input::Mac => tr!("eui64-enter-mac"),
+        Ipv6Input::Prefix => tr!("eui64-enter-prefix"),
+        _ => tr!(
+            "eui64-help",
+            mac = key(Action::FocusMac),
+            prefix = key(Action::FocusCidr)
+        ),
+    };
+    let eui_input = Paragraph::new(vec![
+        Line::from(format!("{}: {}", tr!("field-mac"), tool.mac_input)),
+        Line::from(format!("{}: {}", tr!("field-prefix"), tool.prefix_input)),
+    ])
+    .style(Style::default().fg(Color::Yellow))
+    .block(Block::default().borders(Borders::ALL).title(eui_title));
+
+    let step = |label: String, value: String| Line::from(format!("{:<22}{}", label, value));
+    let steps = match &tool.eui64 {
+        Some(Ok(eui)) => vec![
+            step(tr!("eui64-step-mac"), ipv6::hex_bytes(&eui.mac)),
+            step(tr!("eui64-step-insert"), ipv6::hex_bytes(&eui.padded)),
+            step(tr!("eui64-step-flip"), ipv6::hex_bytes(&eui.flipped)),
+            step(tr!("eui64-step-append"), eui.address.to_string()),
+        ],
+        Some(Err(err)) => vec![Line::styled(err.clone(), Style::default().fg(Color::Red))],
+        None => Vec::new(),
+    };
+    let steps_box = Paragraph::new(steps).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(tr!("eui64-title")),
+    );
+
+    f.render_widget(input_box, chunks[0]);
+    f.render_widget(result_box, chunks[1]);
+    f.render_widget(eui_input, chunks[2]);
+    f.render_widget(steps_box, chunks[3]);
+}
+
+/// `text` with every match of `query` picked out.
+fn highlight(text: String, query: Option<&str>) -> Line<'static> {
+    let Some(query) = query else {
+        return Line::from(text);
+    };
+    let mut spans = Vec::new();
+    let mut at = 0;
+    for found in search::match_ranges(&text, query) {
+        spans.push(Span::raw(text[at..found.start].to_string()));
+        spans.push(Span::styled(
+            text[found.clone()].to_string(),
+            Style::default().fg(Color::Black).bg(Color::Cyan),
+        ));
+        at = found.end;
+    }
+    spans.push(Span::raw(text[at..].to_string()));
+    Line::from(spans)
+}
+
+/// What a list title says about its filter: the query being typed or
+/// applied, or how to start one.
+fn filter_title(filter: &ListFilter, search_key: String) -> String {
+    match (filter.typing, filter.query()) {
+        (true, query) => tr!("filter-typing", query = query.unwrap_or_default()),
+        (false, Some(query)) => tr!("filter-applied", query = query),
+        (false, None) => tr!("filter-hint", key = search_key),
+    }
+}
+
+/// What a list title says about entries it couldn't parse.
+fn ignored(invalid: &[String]) -> String {
+    tr!("list-ignored", entries = invalid.join(", "))
+}
+
+fn draw_arithmetic(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let chunks = panes(
+        area,
+        Direction::Vertical,
+        [Constraint::Length(3), Constraint::Min(0)],
+        1,
+        ws.zoomed,
+    );
+
+    let tool = &ws.arithmetic;
+    let key = |action| keys.hint(Context::Arithmetic, action);
+    let input_title = if tool.typing {
+        tr!("arith-enter")
+    } else {
+        tr!(
+            "arith-help",
+            edit = key(Action::FocusIp),
+            back = key(Action::Back)
+        )
+    };
+    let input_box = Paragraph::new(tool.input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(input_title));
+
+    let lines = match &tool.result {
+        Some(Ok(lines)) => lines.iter().map(|l| Line::from(l.as_str())).collect(),
+        Some(Err(err)) => vec![Line::styled(err.as_str(), Style::default().fg(Color::Red))],
+        None => Vec::new(),
+    };
+    let title = match ws.block() {
+        Some(block) => tr!("arith-title-block", block = block),
+        None => tr!("arith-title"),
+    };
+    let result_box =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(input_box, chunks[0]);
+    f.render_widget(result_box, chunks[1]);
+}
+
+/// The worked solution one step at a time, the steps listed beside it.
+fn draw_explain(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let key = |action| keys.hint(Context::Explain, action);
+    let (Some(ip), Some(prefix)) = (ws.ip_address, ws.prefix_length) else {
+        let empty = Paragraph::new(tr!("explain-empty")).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("explain-title-empty", back = key(Action::Back))),
+        );
+        f.render_widget(empty, area);
+        return;
+    };
+    let steps = explain::steps(ip, prefix, ws.subnetting.as_ref());
+    let current = ws.explain_step.min(steps.len() - 1);
+    let chunks = panes(
+        area,
+        Direction::Horizontal,
+        [Constraint::Length(32), Constraint::Min(0)],
+        1,
+        ws.zoomed,
+    );
+
+    let items: Vec<ListItem> = steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| {
+            let item = ListItem::new(format!("{}. {}", i + 1, step.title));
+            if i == current {
+                item.style(Style::default().fg(Color::Black).bg(Color::Yellow))
+            } else {
+                item
+            }
+        })
+        .collect();
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(tr!(
+        "explain-steps",
+        up = key(Action::Up),
+        down = key(Action::Down)
+    )));
+
+    let step = &steps[current];
+    let mut lines = Vec::new();
+    for (i, row) in step.rows.iter().enumerate() {
+        if let Some(operator) = step.operator.filter(|_| i + 1 == step.rows.len()) {
+            lines.push(Line::styled(
+                format!("{:<10}{}", operator, "─".repeat(35)),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        let mut spans = vec![Span::styled(
+            format!("{:<10}", row.label),
+            Style::default().fg(Color::Cyan),
+        )];
+        spans.extend(bit_spans(row.addr, row.borrowed_from, row.prefix));
+        spans.push(Span::raw(format!("  {}", row.addr)));
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+    lines.extend(step.notes.iter().map(|note| Line::from(note.clone())));
+    let title = tr!(
+        "explain-title",
+        step = current + 1,
+        steps = steps.len(),
+        title = step.title.clone(),
+        back = key(Action::Explain)
+    );
+    let detail = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(list, chunks[0]);
+    f.render_widget(detail, chunks[1]);
+}
+
+fn draw_cleanup(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let chunks = panes(
+        area,
+        Direction::Horizontal,
+        [Constraint::Percentage(50), Constraint::Percentage(50)],
+        1,
+        ws.zoomed,
+    );
+
+    let tool = &ws.cleanup;
+    let key = |action| keys.hint(Context::Cleanup, action);
+    let list_title = if tool.typing {
+        tr!("cleanup-enter")
+    } else {
+        tr!(
+            "cleanup-help",
+            edit = key(Action::EditList),
+            back = key(Action::Back)
+        )
+    };
+    let list_box = Paragraph::new(tool.list_input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(list_title));
+
+    let items: Vec<ListItem> = tool
+        .merged
+        .iter()
+        .map(|b| b.to_string())
+        .filter(|text| tool.filter.keeps(text))
+        .skip(tool.scroll)
+        .map(|text| ListItem::new(highlight(text, tool.filter.query())))
+        .collect();
+    let mut title = tr!(
+        "cleanup-title",
+        entries = tool.entries,
+        unique = tool.unique.len(),
+        merged = tool.merged.len(),
+        addresses = cidr::group_digits(tool.covered()),
+        up = key(Action::Up),
+        down = key(Action::Down)
+    );
+    title.push_str(&filter_title(&tool.filter, key(Action::Search)));
+    if !tool.invalid.is_empty() {
+        title.push_str(&ignored(&tool.invalid));
+    }
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(list_box, chunks[0]);
+    f.render_widget(list, chunks[1]);
+}
+
+/// The host table and the input line below it.
+fn hosts_chunks(area: Rect, zoomed: bool) -> Rc<[Rect]> {
+    panes(
+        area,
+        Direction::Vertical,
+        [Constraint::Min(0), Constraint::Length(3)],
+        0,
+        zoomed,
+    )
+}
+
+/// Host rows that fit in the table on a terminal of `size`, less the
+/// borders and header.
+pub fn hosts_page_size(size: Rect, debug_log: bool, zoomed: bool) -> u64 {
+    let table = hosts_chunks(screen_chunks(size, debug_log)[1], zoomed)[0];
+    u64::from(table.height.saturating_sub(3)).max(1)
+}
+
+fn draw_hosts(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let Some(hosts) = &ws.hosts else {
+        return;
+    };
+    let chunks = hosts_chunks(area, ws.zoomed);
+
+    let page_size = hosts.page_size;
+    let top = hosts.selected / page_size * page_size;
+    let rows = (top..(top + page_size).min(hosts.count)).map(|i| {
+        let marker = if hosts.marked.contains(&i) { "*" } else { " " };
+        let mut cells = vec![
+            Line::from(format!("{}#{}", marker, i + 1)),
+            highlight(hosts.host(i).to_string(), hosts.query.as_deref()),
+        ];
+        if let Some(names) = &ws.names {
+            cells.push(Line::from(names.label(hosts.host(i)).to_string()));
+        }
+        cells.push(Line::from(
+            ws.labels.get(&hosts.host(i)).map_or("", String::as_str),
+        ));
+        let row = Row::new(cells);
+        if i == hosts.selected {
+            row.style(Style::default().fg(Color::Black).bg(Color::Yellow))
+        } else {
+            row
+        }
+    });
+    let mut title = tr!(
+        "hosts-title",
+        selected = cidr::group_digits(hosts.selected + 1),
+        count = cidr::group_digits(hosts.count),
+        page = top / page_size + 1,
+        pages = hosts.count.div_ceil(page_size)
+    );
+    if !hosts.marked.is_empty() {
+        title.push_str(&tr!("hosts-marked", count = hosts.marked.len()));
+    }
+    let (mut widths, mut header) = (
+        vec![Constraint::Length(12), Constraint::Length(16)],
+        vec![tr!("column-host"), tr!("column-address")],
+    );
+    if ws.names.is_some() {
+        widths.push(Constraint::Fill(1));
+        header.push(tr!("column-name"));
+    }
+    widths.push(Constraint::Fill(1));
+    header.push(tr!("column-label"));
+    let table = Table::new(rows, widths)
+        .header(Row::new(header))
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    let key = |action| keys.hint(Context::Hosts, action);
+    let (prompt_title, prompt_text) = match hosts.prompt {
+        Some(HostPrompt::Jump) => (tr!("hosts-enter-jump"), hosts.input.as_str()),
+        Some(HostPrompt::Search) => (tr!("hosts-enter-search"), hosts.input.as_str()),
+        Some(HostPrompt::Port) => (tr!("hosts-enter-port"), hosts.input.as_str()),
+        Some(HostPrompt::Label) => (
+            tr!("hosts-enter-label", host = hosts.host(hosts.selected)),
+            hosts.input.as_str(),
+        ),
+        None => (
+            tr!(
+                "hosts-help",
+                jump = key(Action::JumpToHost),
+                search = key(Action::Search),
+                next = key(Action::NextMatch),
+                mark = key(Action::Toggle),
+                port = key(Action::PortScan),
+                label = key(Action::LabelHost),
+                names = key(Action::ResolveNames),
+                page_up = key(Action::PageUp),
+                page_down = key(Action::PageDown),
+                back = key(Action::Back)
+            ),
+            hosts.message.as_deref().unwrap_or_default(),
+        ),
+    };
+    let prompt_box = Paragraph::new(prompt_text)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(prompt_title));
+
+    f.render_widget(table, chunks[0]);
+    f.render_widget(prompt_box, chunks[1]);
+}
+
+fn draw_quiz(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let Some(quiz) = &ws.quiz else {
+        return;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(4),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let question_box = Paragraph::new(quiz.question.text()).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(tr!("quiz-question")),
+    );
+    let answer_box = Paragraph::new(quiz.answer_input.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("quiz-answer")),
+        );
+
+    let (verdict, color) = match quiz.graded {
+        None => (String::new(), Color::Reset),
+        Some(true) => (
+            tr!(
+                "quiz-correct",
+                key = keys.hint(Context::Quiz, Action::Submit)
+            ),
+            Color::Green,
+        ),
+        Some(false) => (
+            tr!(
+                "quiz-wrong",
+                answer = quiz.question.answer(),
+                key = keys.hint(Context::Quiz, Action::Submit)
+            ),
+            Color::Red,
+        ),
+    };
+    let verdict_box = Paragraph::new(verdict)
+        .style(Style::default().fg(color))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("quiz-result")),
+        );
+
+    let score_box = Paragraph::new(tr!(
+        "quiz-score",
+        correct = quiz.correct,
+        asked = quiz.asked,
+        streak = quiz.streak,
+        best = quiz.best_streak
+    ))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(tr!("quiz-title")),
+    );
+
+    f.render_widget(question_box, chunks[0]);
+    f.render_widget(answer_box, chunks[1]);
+    f.render_widget(verdict_box, chunks[2]);
+    f.render_widget(score_box, chunks[3]);
+}
+
+fn draw_sweep(f: &mut Frame, area: Rect, ws: &Workspace, keys: &Keymap) {
+    let Some(sweep) = &ws.sweep else {
+        return;
+    };
+    let key = |action| keys.hint(Context::Sweep, action);
+    let chunks = panes(
+        area,
+        Direction::Vertical,
+        [Constraint::Length(3), Constraint::Min(0)],
+        1,
+        ws.zoomed,
+    );
+
+    let total = sweep.targets.len();
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(tr!("sweep-progress")),
+        )
+        .gauge_style(Style::default().fg(Color::Cyan))
+        .ratio(sweep.finished as f64 / total.max(1) as f64)
+        .label(tr!(
+            "sweep-rate",
+            finished = sweep.finished,
+            total = total,
+            rate = format!("{:.1}", sweep.rate())
+        ));
+
+    let filter = &sweep.filter;
+    let rows = sweep
+        .targets
+        .iter()
+        .zip(&sweep.results)
+        .map(|(ip, outcome)| {
+            let (status, rtt, color) = match outcome {
+                Outcome::Pending => ("...".to_string(), String::new(), Color::DarkGray),
+                Outcome::Up(rtt) => (
+                    tr!("sweep-up"),
+                    format!("{:.1} ms", rtt.as_secs_f64() * 1000.0),
+                    Color::Green,
+                ),
+                Outcome::Open(rtt) => (
+                    tr!("sweep-open"),
+                    format!("{:.1} ms", rtt.as_secs_f64() * 1000.0),
+                    Color::Green,
+                ),
+                Outcome::Closed => (tr!("sweep-closed"), String::new(), Color::Yellow),
+                Outcome::Down if matches!(sweep.probe, Probe::Port(_)) => {
+                    (tr!("sweep-no-answer"), String::new(), Color::Red)
+                }
+                Outcome::Down => (tr!("sweep-down"), String::new(), Color::Red),
+            };
+            let mut cells = vec![ip.to_string()];
+            if let Some(names) = &ws.names {
+                cells.push(names.label(*ip).to_string());
+            }
+            cells.extend([status, rtt]);
+            (cells, color)
+        })
+        .filter(|(cells, _)| filter.keeps(&cells.join(" ")))
+        .skip(sweep.scroll)
+        .take(chunks[1].height as usize)
+        .map(|(cells, color)| {
+            Row::new(
+                cells
+                    .into_iter()
+                    .map(|cell| highlight(cell, filter.query())),
+            )
+            .style(Style::default().fg(color))
+        });
+    let kind = match sweep.probe {
+        Probe::Port(_) => tr!("sweep-kind-scan"),
+        Probe::Icmp | Probe::Tcp(_) => tr!("sweep-kind-sweep"),
+    };
+    let state = match (sweep.is_running(), sweep.cancelled) {
+        (true, _) => tr!("sweep-running"),
+        (false, true) => tr!("sweep-cancelled"),
+        (false, false) => tr!("sweep-done"),
+    };
+    let back = if sweep.is_running() {
+        tr!("sweep-cancel")
+    } else {
+        tr!("sweep-go-back")
+    };
+    let title = tr!(
+        "sweep-title",
+        probe = sweep.probe,
+        kind = kind,
+        state = state,
+        answered = sweep.answered(),
+        answer = sweep.probe.answer_label(),
+        finished = sweep.finished,
+        total = sweep.targets.len(),
+        switch = key(Action::SwitchProbe),
+        names = key(Action::ResolveNames),
+        up = key(Action::Up),
+        down = key(Action::Down),
+        back_key = key(Action::Back),
+        back = back
+    ) + &filter_title(filter, key(Action::Search));
+    let (mut widths, mut header) = (vec![Constraint::Length(16)], vec![tr!("column-host")]);
+    if ws.names.is_some() {
+        widths.push(Constraint::Length(40));
+        header.push(tr!("column-name"));
+    }
+    widths.extend([Constraint::Length(10), Constraint::Length(12)]);
+    header.extend([tr!("column-status"), tr!("column-rtt")]);
+    let table = Table::new(rows, widths)
+        .header(Row::new(header))
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(gauge, chunks[0]);
+    f.render_widget(table, chunks[1]);
+}
@@ -0,0 +1,71 @@
+use std::{
+    fs,
+    net::Ipv6Addr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{i18n::tr, ipv6};
+
+/// Seconds between the NTP epoch (1900) and the Unix epoch.
+const NTP_UNIX_OFFSET: u64 = 2_208_988_800;
+
+/// A freshly generated Unique Local Address /48 and how its Global ID
+/// was made.
+pub struct Ula {
+    pub prefix: Ipv6Addr,
+    pub source: String,
+}
+
+/// Generates an `fd00::/8` /48 with the RFC 4193 section 3.2.2 algorithm:
+/// the low 40 bits of SHA-1 over the NTP time and an EUI-64 from one of
+/// the machine's MACs. Without a MAC to hash, the Global ID comes from
+/// the OS random number generator instead.
+pub fn generate() -> Result<Ula, String> {
+    let (global_id, source) = match system_mac() {
+        Some((interface, mac)) => {
+            let mut data = ntp_timestamp().to_be_bytes().to_vec();
+            data.extend(ipv6::eui64(mac, Ipv6Addr::UNSPECIFIED).flipped);
+            let digest = sha1_smol::Sha1::from(&data).digest().bytes();
+            (
+                digest[15..].to_vec(),
+                tr!("ula-source-mac", interface = interface),
+            )
+        }
+        None => {
+            let mut bytes = [0; 5];
+            getrandom::fill(&mut bytes).map_err(|err| tr!("ula-failed", error = err))?;
+            (bytes.to_vec(), tr!("ula-source-random"))
+        }
+    };
+    let mut octets = [0; 16];
+    octets[0] = 0xfd;
+    octets[1..6].copy_from_slice(&global_id);
+    Ok(Ula {
+        prefix: Ipv6Addr::from(octets),
+        source,
+    })
+}
+
+fn ntp_timestamp() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let seconds = now.as_secs() + NTP_UNIX_OFFSET;
+    let fraction = (u64::from(now.subsec_nanos()) << 32) / 1_000_000_000;
+    seconds << 32 | fraction
+}
+
+/// The first non-zero MAC among the network interfaces, with its name.
+/// Only Linux exposes these without extra privileges or crates.
+fn system_mac() -> Option<(String, [u8; 6])> {
+    let mut interfaces: Vec<_> = fs::read_dir("/sys/class/net")
+        .ok()?
+        .filter_map(Result::ok)
+        .collect();
+    interfaces.sort_by_key(|entry| entry.file_name());
+    interfaces.into_iter().find_map(|entry| {
+        let address = fs::read_to_string(entry.path().join("address")).ok()?;
+        let mac = ipv6::parse_mac(&address).filter(|mac| mac.iter().any(|&b| b != 0))?;
+        Some((entry.file_name().to_string_lossy().into_owned(), mac))
+    })
+}